@@ -12,3 +12,13 @@ try:
     pass
 except ValueError:
     pass
+
+try:
+    pass
+except Exception:
+    ...
+
+try:
+    pass
+except Exception:
+    logger.exception("Exception occurred")
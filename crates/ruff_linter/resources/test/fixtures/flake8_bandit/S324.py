@@ -12,12 +12,16 @@ hashlib.new('sha1')
 hashlib.new('sha1', data=b'test')
 hashlib.new('sha', data=b'test')
 hashlib.new(name='SHA', data=b'test')
+hashlib.new('Md5')
+hashlib.new('md2')
+hashlib.new(name='Sha1', data=b'test')
 hashlib.sha(data=b'test')
 hashlib.md5()
 hashlib_new('sha1')
 hashlib_sha1('sha1')
 # usedforsecurity arg only available in Python 3.9+
 hashlib.new('sha1', usedforsecurity=True)
+hashlib.md5(usedforsecurity=True)
 
 crypt.crypt("test", salt=crypt.METHOD_CRYPT)
 crypt.crypt("test", salt=crypt.METHOD_MD5)
@@ -36,7 +40,9 @@ hashlib.sha256(data=b'test')
 hashlib_new(name='sha1', usedforsecurity=False)
 hashlib_sha1(name='sha1', usedforsecurity=False)
 hashlib.md4(usedforsecurity=False)
+hashlib.md5(b'test', usedforsecurity=False)
 hashlib.new(name='sha256', usedforsecurity=False)
+hashlib.new('md2', usedforsecurity=False)
 
 crypt.crypt("test")
 crypt.crypt("test", salt=crypt.METHOD_SHA256)
@@ -311,4 +311,17 @@ def single_line_func_wrong(value: dict[str, str] = {}) \
 
 
 def single_line_func_wrong(value: dict[str, str] = {}):
-    """Docstring without newline"""
\ No newline at end of file
+    """Docstring without newline"""
+
+# B008: the default is computed once, at definition time.
+def default_from_call(value=compute()):
+    pass
+
+
+# OK: immutable constructors are deterministic and safe to share.
+def default_from_tuple(value=tuple()):
+    pass
+
+
+def default_from_frozenset(value=frozenset()):
+    pass
@@ -9,3 +9,5 @@ warnings.warn(DeprecationWarning("test"))
 warnings.warn(DeprecationWarning("test"), source=None)
 warnings.warn(DeprecationWarning("test"), source=None, stacklevel=2)
 warnings.warn(DeprecationWarning("test"), stacklevel=1)
+warnings.warn(DeprecationWarning("test"), stacklevel=3)
+warnings.warn(DeprecationWarning("test"), DeprecationWarning, 2)
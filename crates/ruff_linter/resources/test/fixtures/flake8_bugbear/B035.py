@@ -12,6 +12,7 @@ constant = 5
 {value[constant]: value.upper() for value in data for constant in data}
 {local_id: token for token in tokens if (local_id := _extract_local_id(token)) is not None}
 {key: kwargs.get(key) for key in kwargs.keys() if not params.get(key)}
+{k: v for k, v in pairs}  # Tuple-unpacked target
 
 # Errors
 {"key": value.upper() for value in data}
@@ -23,4 +24,5 @@ constant = 5
 {constant.attribute: value.upper() for value in data}
 {constant[0]: value.upper() for value in data}
 {tokens: token for token in tokens}
+{constant: v for k, v in pairs}  # Key ignores both unpacked targets
 
@@ -71,3 +71,17 @@ except Exception as e:
     match 0:
         case 0:
             raise RuntimeError("boom!")
+
+
+def chained_without_from():
+    try:
+        ...
+    except ValueError as err:
+        raise RuntimeError("failed")  # B904 (fixed with `from err`)
+
+
+def already_chained():
+    try:
+        ...
+    except ValueError as err:
+        raise RuntimeError("failed") from err
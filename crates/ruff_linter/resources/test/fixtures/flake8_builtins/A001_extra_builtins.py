@@ -0,0 +1,5 @@
+List = [1, 2]
+Dict = {}
+Optional = None
+Sequence = ()
+print = "builtin"
@@ -0,0 +1,2 @@
+def func(List, Sequence, id):
+    ...
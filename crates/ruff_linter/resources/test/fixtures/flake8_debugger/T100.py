@@ -23,3 +23,4 @@ debugpy.listen(1234)
 enable_attach()
 break_into_debugger()
 wait_for_attach()
+value = breakpoint()  # flagged, but no fix: not a standalone statement
@@ -1 +1,8 @@
 _(f"{'value'}")
+_(f"Hello {name}")  # INT001
+gettext(f"Hello {name}")  # INT001
+ngettext(f"{n} item", f"{n} items", n)  # INT001
+
+# OK: formatted after translation.
+_("Hello %s") % name
+_("Hello {}").format(name)
@@ -6,6 +6,7 @@ logging.getLogger(__name__)
 logging.getLogger(name=__name__)
 logging.getLogger("custom")
 logging.getLogger(name="custom")
+logging.getLogger(spec.name)
 
 # LOG002
 getLogger(__file__)
@@ -14,6 +15,9 @@ logging.getLogger(name=__file__)
 logging.getLogger(__cached__)
 getLogger(name=__cached__)
 
+logging.getLogger(__spec__.name)
+getLogger(name=__spec__.name)
+
 
 # Override `logging.getLogger`
 class logging:
@@ -0,0 +1,10 @@
+import logging
+
+# Ok
+logging.getLogger(__name__)
+logging.getLogger("other.module")
+logging.getLogger("log002_package")
+
+# LOG002
+logging.getLogger("log002_package.module")
+logging.getLogger(name="log002_package.module")
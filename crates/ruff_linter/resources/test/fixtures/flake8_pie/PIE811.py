@@ -0,0 +1,20 @@
+pairs = [("a", 1), ("b", 2)]
+
+# PIE811
+foo = dict({key: value for key, value in pairs})
+bar = dict(
+    {key: value for key, value in pairs}
+)
+
+# OK
+baz = {key: value for key, value in pairs}
+qux = dict({key: value for key, value in pairs}, extra=1)
+quux = dict(pairs)
+corge = dict({"a": 1})
+
+
+def dict(mapping):
+    return mapping
+
+
+grault = dict({key: value for key, value in pairs})  # OK: `dict` is shadowed
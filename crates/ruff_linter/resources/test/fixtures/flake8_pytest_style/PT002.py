@@ -19,3 +19,13 @@ def my_fixture():  # Error only args
 @pytest.fixture("module", autouse=True)
 def my_fixture():  # Error mixed
     return 0
+
+
+@pytest.fixture("module", ["a", "b"])
+def my_fixture():  # Error multiple args, no fix
+    return 0
+
+
+@pytest.fixture("module", scope="module")
+def my_fixture():  # Error `scope` keyword already present, no fix
+    return 0
@@ -0,0 +1,20 @@
+def inlinable():
+    result: int = compute()
+    return result  # RET504 (with flag-annotated-assigns)
+
+
+def used_elsewhere():
+    result: int = compute()
+    log(result)
+    return result  # OK
+
+
+def bare_annotation():
+    result: int
+    result = compute()
+    return result  # OK (annotation defined elsewhere)
+
+
+def unannotated():
+    result = compute()
+    return result  # RET504
@@ -238,3 +238,19 @@ def indent(x, y, w, z):
   # comment
         c = 3
         return z
+
+
+def elif_chain(x):
+    if x == 1:  # [no-else-return]
+        return "one"
+    elif x == 2:
+        return "two"
+    else:
+        return "many"
+
+
+def mixed_terminals(x):
+    if x:  # [no-else-return]
+        raise ValueError(x)
+    else:
+        return None
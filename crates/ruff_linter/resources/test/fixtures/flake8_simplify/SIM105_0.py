@@ -128,3 +128,11 @@ def write_models(directory, Models):
         pass; \
             \
             #
+
+
+# OK: multi-statement try bodies aren't a `suppress` candidate.
+try:
+    first()
+    second()
+except ValueError:
+    pass
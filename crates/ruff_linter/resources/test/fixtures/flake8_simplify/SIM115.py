@@ -46,3 +46,26 @@ with contextlib.ExitStack() as exit_stack:
 # OK (quick one-liner to clear file contents)
 open("filename", "w").close()
 pathlib.Path("filename").open("w").close()
+
+
+# OK (explicitly closed)
+def read_all(path):
+    f = open(path)
+    data = f.read()
+    f.close()
+    return data
+
+
+# OK (closed in `finally`)
+def read_all(path):
+    f = open(path)
+    try:
+        return f.read()
+    finally:
+        f.close()
+
+
+# SIM115
+def read_all(path):
+    f = open(path)
+    return f.read()
@@ -63,3 +63,9 @@ from builtins import dict as SneakyDict
 
 d = SneakyDict()
 key in d.keys()  # SIM118
+
+for i, key in enumerate(d.keys()):  # SIM118
+    pass
+
+for key in list(d.keys()):  # OK (makes a copy, e.g. to mutate `d` while iterating)
+    del d[key]
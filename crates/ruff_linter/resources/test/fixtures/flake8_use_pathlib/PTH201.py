@@ -6,10 +6,13 @@ _ = Path(".")
 _ = pth(".")
 _ = PurePath(".")
 _ = Path("")
+_ = Path(".", "folder")
+_ = PurePath(".", "folder")
+_ = Path("", "folder", "file.txt")
 
 # no match
 _ = Path()
 print(".")
 Path("file.txt")
-Path(".", "folder")
-PurePath(".", "folder")
+Path("folder", ".")
+Path(subdir, ".")
@@ -0,0 +1,16 @@
+import os
+
+segments = ["a", "b", "c"]
+
+path = "base"
+for segment in segments:
+    path = os.path.join(path, segment)  # PTH208
+
+while more_segments():
+    path = os.path.join(path, next_segment())  # PTH208
+
+# A single join is handled by PTH118; this rule only fires on accumulation.
+single = os.path.join("base", "leaf")
+
+for segment in segments:
+    other = os.path.join(path, segment)  # OK (not accumulating onto itself)
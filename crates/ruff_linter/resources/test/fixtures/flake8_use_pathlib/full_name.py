@@ -34,6 +34,16 @@ with open(p) as fp:
 open(p).close()
 os.getcwdb(p)
 os.path.join(p, *q)
+os.path.join(os.path.join(p, q), r)
+os.path.join(p, q + r)
+os.path.relpath(p)
+os.path.relpath(p, q)
+os.path.relpath(p, start=q)
+
+from pathlib import PureWindowsPath
+
+pure = PureWindowsPath("C:/base")
+os.path.join(pure, q)
 os.sep.join(p, *q)
 
 # https://github.com/astral-sh/ruff/issues/7620
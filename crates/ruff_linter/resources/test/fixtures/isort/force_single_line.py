@@ -25,3 +25,9 @@ from bar import (
 
 # comment 9
 from baz import *  # comment 10
+
+from qux import (
+    c,  # noqa: F401
+    d,
+)
+from quux import e as f, g  # noqa
@@ -105,3 +105,13 @@ def f():
 def _create_context(name_to_value):
     for(B,D)in A.items():
         if(C:=name_to_value.get(B.name)):A.run(B.set,C)
+
+
+async def f():
+    async for _, value in some_dict.items():  # PERF102
+        print(value)
+
+
+async def f():
+    async for key, value in some_dict.items():  # OK
+        print(key, value)
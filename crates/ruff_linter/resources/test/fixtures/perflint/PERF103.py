@@ -0,0 +1,26 @@
+items = [3, 1, 2]
+
+
+sorted([3, 1, 2])  # PERF103 (fixable)
+
+sorted(("b", "a", "c"))  # PERF103 (fixable)
+
+sorted([3, 1, 2], reverse=True)  # PERF103 (fixable)
+
+sorted([-1, 2.5, 0])  # PERF103 (fixable)
+
+reversed([1, 2, 3])  # PERF103 (no fix; returns an iterator)
+
+sorted(items)  # OK (not a literal)
+
+sorted([3, 1, 2], key=abs)  # OK (key callback)
+
+sorted([1, "a"])  # OK (elements aren't uniformly comparable)
+
+sorted([f(), 1])  # OK (non-constant element)
+
+sorted([3j, 1j])  # OK (complex numbers aren't orderable)
+
+sorted([3, 1], reverse=flag)  # OK (non-literal reverse)
+
+reversed([1, 2], strict=True)  # OK (unexpected keyword)
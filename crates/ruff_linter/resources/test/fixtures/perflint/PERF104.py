@@ -0,0 +1,35 @@
+def direct(fruits):
+    for i in range(len(fruits)):  # PERF104: fixable to direct iteration
+        print(fruits[i])
+
+
+def with_index(prices):
+    total = 0
+    for i in range(len(prices)):  # PERF104: `enumerate`, but no fix
+        total += i * prices[i]
+    return total
+
+
+def mutates(values):
+    for i in range(len(values)):  # OK: assigns through the index
+        values[i] = 0
+
+
+def plain_counter(items):
+    for i in range(len(items)):  # OK: the sequence is never indexed
+        print(i)
+
+
+def range_with_step(items):
+    for i in range(0, len(items), 2):  # OK: not a bare `range(len(...))`
+        print(items[i])
+
+
+async def async_loop(items):
+    async for i in range(len(items)):  # OK: async iteration
+        print(items[i])
+
+
+def shadowed(items, items_item):
+    for i in range(len(items)):  # PERF104: no fix, `items_item` is taken
+        print(items[i])
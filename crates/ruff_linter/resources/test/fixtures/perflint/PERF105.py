@@ -0,0 +1,18 @@
+import copy
+
+# PERF105
+fresh_list = copy.copy([1, 2])
+fresh_dict = copy.copy({"a": 1})
+fresh_set = copy.copy({1, 2})
+fresh_comp = copy.copy([x for x in range(3)])
+fresh_call = copy.copy(dict(a=1))
+fresh_method = {"a": 1}.copy()
+fresh_method_call = dict(a=1).copy()
+
+# OK
+existing = [1, 2]
+_ = copy.copy(existing)
+_ = existing.copy()
+_ = copy.deepcopy([1, [2]])  # `deepcopy` also copies the nested objects
+_ = copy.copy((1, 2))  # tuples are immutable, but `copy.copy` returns them as-is
+_ = copy.copy()
@@ -25,3 +25,8 @@ k = {}
 '%(k)s' % {**k}
 '%s' % [1, 2, 3]
 '%s' % {1, 2, 3}
+
+'Hello, %S' % 'world'  # F509, fixable to `%s`
+'%R' % (1,)  # F509, fixable to `%r`
+'%q' % (1,)  # F509, no case-only counterpart
+'\n%S' % 'world'  # F509, escape prevents locating the character
@@ -2,3 +2,6 @@ assert (False, "x")
 assert (False,)
 assert ()
 assert True
+assert (some_condition,)
+assert (some_condition,), "message"
+assert some_condition, "message"
@@ -29,3 +29,19 @@ not ''}
 # Regression test for
 if values[1is not None ] is not '-':
     pass
+
+if x is 5:
+    pass
+
+if x is ():
+    pass
+
+# Singletons are valid identity comparisons, and are never flagged.
+if x is None:
+    pass
+
+if x is True:
+    pass
+
+if x is not False:
+    pass
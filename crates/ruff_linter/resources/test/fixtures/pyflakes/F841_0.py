@@ -151,3 +151,17 @@ def f():
         pass
     except Exception as _:
         pass
+
+
+def f():
+    try:
+        pass
+    except (ValueError, TypeError) as err:
+        pass
+
+
+def f():
+    try:
+        pass
+    except ValueError as error:
+        raise error
@@ -22,3 +22,5 @@ assert b"hello"  # [assert-on-string-literal]
 assert "", b"hi"  # [assert-on-string-literal]
 assert "WhyNotHere?", "HereIsOk"  # [assert-on-string-literal]
 assert 12, "ok here"
+assert "not implemented"  # [assert-on-string-literal]
+assert x, "msg"
@@ -14,6 +14,9 @@ open(NAME, "rwx", encoding="utf-8")  # [bad-open-mode]
 open(NAME, "rr", encoding="utf-8")  # [bad-open-mode]
 open(NAME, "+", encoding="utf-8")  # [bad-open-mode]
 open(NAME, "xw", encoding="utf-8")  # [bad-open-mode]
+open(NAME, "rw")  # [bad-open-mode]
+open(NAME, "rb+t")  # [bad-open-mode]
+open(NAME, "w+b")
 open(NAME, "ab+")
 open(NAME, "a+b")
 open(NAME, "+ab")
@@ -43,3 +43,24 @@ def f():
     global X
 
     X += 1
+
+
+###
+# Errors: mutated via method calls, but never rebound.
+###
+def f():
+    global lst
+
+    lst.append(1)
+
+
+def f():
+    global mapping
+
+    mapping["key"] = "value"
+
+
+def f():
+    global X, Y
+
+    print(X, Y)
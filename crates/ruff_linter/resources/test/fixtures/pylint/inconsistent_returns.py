@@ -0,0 +1,83 @@
+from typing import Any, Optional, Union
+
+
+def find(items, key):  # PLR1710: falls off the end.
+    for item in items:
+        if item.key == key:
+            return item
+
+
+def lookup(mapping, key):  # PLR1710: bare `return` mixed with a value.
+    if key not in mapping:
+        return
+    return mapping[key]
+
+
+def partial(x):  # PLR1710: `else` branch falls through.
+    if x:
+        return 1
+    elif x > 10:
+        return 2
+
+
+# OK: all paths return a value.
+def both(x):
+    if x:
+        return 1
+    return 2
+
+
+# OK: explicit `return None` is a value return.
+def explicit(x):
+    if x:
+        return 1
+    return None
+
+
+# OK: never returns a value.
+def procedure(x):
+    if not x:
+        return
+    do_something(x)
+
+
+# OK: raising paths don't count as implicit returns.
+def raises(x):
+    if not x:
+        raise ValueError
+    return x
+
+
+# OK: generators are excluded.
+def generate(items):
+    for item in items:
+        if item:
+            return
+        yield item
+
+
+# OK: the annotation explicitly allows `None`.
+def annotated_optional(x) -> Optional[int]:
+    if x:
+        return 1
+
+
+def annotated_union(x) -> Union[int, None]:
+    if x:
+        return 1
+
+
+def annotated_pep_604(x) -> int | None:
+    if x:
+        return 1
+
+
+def annotated_any(x) -> Any:
+    if x:
+        return 1
+
+
+# PLR1710: the annotation rules out `None`.
+def annotated_int(x) -> int:
+    if x:
+        return 1
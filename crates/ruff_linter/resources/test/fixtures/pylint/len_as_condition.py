@@ -0,0 +1,40 @@
+fruits = ["orange", "apple"]
+
+if len(fruits):  # PLC1802
+    pass
+
+while len(fruits):  # PLC1802
+    pass
+
+assert len(fruits)  # PLC1802
+
+if not len(fruits):  # PLC1802
+    pass
+
+if len(fruits) and True:  # PLC1802
+    pass
+
+x = 1 if len(fruits) else 2  # PLC1802
+
+if len(fruits.copy()):  # PLC1802
+    pass
+
+if len(fruits or []):  # PLC1802 (no fix: operand isn't an atom)
+    pass
+
+# OK
+if len(fruits) == 3:
+    pass
+
+if len(fruits) > 0:
+    pass
+
+if fruits:
+    pass
+
+length = len(fruits)
+
+print(len(fruits))
+
+if len(fruits, "unexpected"):  # Not `len` as documented.
+    pass
@@ -80,3 +80,11 @@ if y == np.inf:
 # OK
 if x == "nan":
     pass
+
+# OK: identity comparison, not an equality check.
+if x is math.nan:
+    pass
+
+# OK: ordering comparison, not an equality check.
+if x > float("nan"):
+    pass
@@ -5,6 +5,8 @@ foo != "a" and foo != "b"
 
 foo == "a" or foo == "b" or foo == "c"
 
+foo == 1 or foo == 2 or foo == 3 or foo == 4
+
 foo != "a" and foo != "b" and foo != "c"
 
 foo == a or foo == "b" or foo == 3  # Mixed types.
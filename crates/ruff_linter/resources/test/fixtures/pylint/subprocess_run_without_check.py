@@ -16,3 +16,5 @@ subprocess.run("ls", shell=True, check=True)
 subprocess.run("ls", shell=True, check=False)
 foo.run("ls")  # Not a subprocess.run call.
 subprocess.bar("ls")  # Not a subprocess.run call.
+result = subprocess.run("ls")  # Result is bound; assumed to be inspected.
+result: subprocess.CompletedProcess = subprocess.run("ls")
@@ -57,3 +57,7 @@ _ = lambda x: z(lambda y: x + y)(x)
 # lambda uses an additional keyword
 _ = lambda *args: f(*args, y=1)
 _ = lambda *args: f(*args, y=x)
+
+# lambda has a keyword-only parameter, which is not forwarded to the call
+_ = lambda *args, x, **kwargs: f(*args, **kwargs)
+_ = lambda *args, x=1, **kwargs: f(*args, **kwargs)
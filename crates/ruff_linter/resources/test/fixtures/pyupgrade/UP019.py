@@ -18,3 +18,7 @@ def print_third_word(word: Hello.Text) -> None:
 
 def print_fourth_word(word: Goodbye) -> None:
     print(word)
+
+
+def print_words(words: typing.List[Text]) -> None:
+    print(*words)
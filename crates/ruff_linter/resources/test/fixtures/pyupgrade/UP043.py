@@ -0,0 +1,14 @@
+a = {"x": 1}
+b = {"y": 2}
+c = {"z": 3}
+
+{**a, **b}  # UP043
+{**a, **b, **c}  # UP043
+{**a, **{"y": 2}}  # UP043
+{**a, **(b if flag else c)}  # UP043
+{**get_defaults(), **b}  # UP043
+
+{**a}  # OK (no merge)
+{**a, "k": 1}  # OK (literal key interleaved)
+{"k": 1, **a}  # OK (literal key interleaved)
+{"x": 1, "y": 2}  # OK
@@ -23,3 +23,19 @@ class B:
     correct_code: list[int] = KNOWINGLY_MUTABLE_DEFAULT
     perfectly_fine: list[int] = field(default_factory=list)
     class_variable: ClassVar[list[int]] = []
+
+
+def make_list() -> list[int]:
+    return []
+
+
+def compute() -> int:
+    return 7
+
+
+@dataclass
+class C:
+    mutable_call: list[int] = make_list()
+    mutable_call_with_args: list[int] = make_list(1)
+    not_mutable_annotation: int = compute()
+    perfectly_fine: list[int] = field(default_factory=make_list)
@@ -0,0 +1,11 @@
+from __future__ import annotations
+
+from typing import Optional
+
+
+def f(arg: int = None):  # RUF013
+    pass
+
+
+def g(arg: Optional[int] = None):
+    pass
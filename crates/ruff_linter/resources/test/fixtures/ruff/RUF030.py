@@ -0,0 +1,30 @@
+from collections.abc import Iterator
+
+
+def f() -> None:  # RUF030
+    yield 1
+
+
+def g() -> int:  # RUF030
+    yield from f()
+
+
+async def h() -> None:  # RUF030
+    yield 1
+
+
+def i() -> Iterator[int]:  # OK
+    yield 1
+
+
+def j() -> None:  # OK (the `yield` belongs to the nested function)
+    def inner() -> Iterator[int]:
+        yield 1
+
+
+def k() -> None:  # OK (no `yield` at all)
+    return None
+
+
+def l():  # OK (unannotated)
+    yield 1
@@ -0,0 +1,35 @@
+import os
+
+if os.getenv("DEBUG"):  # RUF031
+    pass
+
+if os.environ.get("DEBUG"):  # RUF031
+    pass
+
+if os.getenv("DEBUG", None):  # RUF031 (explicit `None` default changes nothing)
+    pass
+
+if not os.getenv("DEBUG"):  # RUF031
+    pass
+
+if os.getenv("DEBUG") and flag:  # RUF031
+    pass
+
+while os.environ.get("KEEP_GOING"):  # RUF031
+    pass
+
+x = 1 if os.getenv("DEBUG") else 2  # RUF031
+
+# OK
+if os.getenv("DEBUG") == "1":
+    pass
+
+if os.getenv("DEBUG", "0") == "1":
+    pass
+
+if os.getenv("DEBUG", "fallback"):  # explicit default: the author chose the semantics
+    pass
+
+debug = os.getenv("DEBUG")
+
+print(os.getenv("DEBUG"))
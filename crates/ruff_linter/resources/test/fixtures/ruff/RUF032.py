@@ -0,0 +1,13 @@
+assert x, ("msg", detail)  # RUF032
+assert x, ("msg",)  # RUF032
+assert x, (
+    "first part",  # RUF032
+    "second part",
+)
+
+# OK
+assert x, "msg"
+assert x, f"msg: {detail}"
+assert x, "msg: %s" % detail
+assert x
+assert (x, y)  # F631, not RUF032: the tuple is the condition.
@@ -0,0 +1,41 @@
+class C:
+    @staticmethod
+    @staticmethod  # RUF033
+    def f():
+        pass
+
+    @property
+    @property  # RUF033
+    def g(self):
+        return 1
+
+
+@app.route("/")
+@app.route("/")  # RUF033, unless flag-duplicate-call-decorators is disabled
+def index():
+    pass
+
+
+@decorate
+@decorate  # RUF033
+class D:
+    pass
+
+
+@functools.cache
+@functools.cache
+def cached():
+    pass
+
+
+# OK
+@app.route("/")
+@app.route("/home")
+def home():
+    pass
+
+
+@functools.cache
+@staticmethod
+def h():
+    pass
@@ -0,0 +1,32 @@
+import contextlib
+
+# RUF034: three context managers on an overlong line.
+with open("first.txt") as first, open("second.txt") as second, open("third.txt") as third:
+    pass
+
+# RUF034: no `as` bindings.
+with contextlib.suppress(ValueError), contextlib.suppress(TypeError), contextlib.suppress(KeyError):
+    pass
+
+
+async def main():
+    # RUF034: also applies to `async with`.
+    async with connect("first") as first, connect("second") as second, connect("third") as third:
+        pass
+
+
+# OK: only two context managers.
+with open("first-long-name.txt") as first, open("second-much-longer-name.txt") as second:
+    pass
+
+# OK: fits on the line.
+with open("a") as a, open("b") as b, open("c") as c:
+    pass
+
+# OK: already parenthesized.
+with (
+    open("first.txt") as first,
+    open("second.txt") as second,
+    open("third.txt") as third,
+):
+    pass
@@ -0,0 +1,16 @@
+assert (x := compute()), x  # RUF035
+assert (result := fetch()), f"unexpected result: {result}"  # RUF035
+assert (value := get()), ("value", value)  # RUF035
+
+# OK: the walrus is part of a larger condition.
+assert (x := compute()) > 0, x
+assert (x := compute()) is not None, f"got {x}"
+
+# OK: the name isn't used in the message.
+assert (x := compute()), "computation failed"
+
+# OK: no message.
+assert (x := compute())
+
+# OK: no walrus.
+assert compute(), "computation failed"
@@ -0,0 +1,22 @@
+import typing
+from typing import Optional
+
+
+def f(x: Optional[int], y: str | None) -> None:
+    pass
+
+
+def g(x: typing.Optional[str]) -> int | None:
+    return None
+
+
+value: Optional[dict[str, int]] = None
+nested: dict[str, Optional[int]] = {}
+
+
+def h(callback: Optional["Handler"] = None) -> None:
+    pass
+
+
+# Not an annotation: never flagged.
+flags = READ | None if dynamic else WRITE
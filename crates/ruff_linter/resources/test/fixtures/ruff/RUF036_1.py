@@ -0,0 +1,10 @@
+from typing import Optional
+
+
+# Consistent: only `Optional[X]` is used, so nothing is flagged regardless of
+# the configured preference.
+def f(x: Optional[int], y: Optional[str]) -> Optional[bytes]:
+    return None
+
+
+value: Optional[dict[str, int]] = None
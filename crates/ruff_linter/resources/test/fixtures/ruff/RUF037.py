@@ -0,0 +1,14 @@
+value = 42
+items = [1, 2, 3]
+
+f"{value=}"  # RUF037 (fixable)
+f"{value = }"  # RUF037 (fixable)
+f"{value=:>10}"  # RUF037 (fixable)
+f"{value=!r}"  # RUF037 (fixable)
+f"{items[0]=}"  # RUF037 (fixable)
+f"before {value=} after"  # RUF037 (fixable)
+
+f"{value}"  # OK
+f"{value!r}"  # OK
+f"{value:>10}"  # OK
+"{value=}"  # OK (not an f-string)
@@ -0,0 +1,52 @@
+from abc import ABC, abstractmethod
+from functools import wraps
+
+
+class Good(ABC):
+    @property
+    @abstractmethod
+    def bar(self):
+        ...
+
+    @staticmethod
+    def baz():
+        ...
+
+    @classmethod
+    @property
+    def qux(cls):
+        ...
+
+
+class Bad(ABC):
+    @abstractmethod
+    @property
+    def bar(self):  # RUF038 (fixable)
+        ...
+
+    @abstractmethod
+    @classmethod
+    def baz(cls):  # RUF038 (fixable)
+        ...
+
+    @property
+    @staticmethod
+    def qux():  # RUF038
+        ...
+
+    @property
+    @classmethod
+    def quux(cls):  # RUF038
+        ...
+
+    @abstractmethod
+    @wraps(bar)
+    def corge(self):  # OK (unknown decorators may compose fine)
+        ...
+
+
+# Not in a class: never flagged.
+@property
+@staticmethod
+def standalone():
+    ...
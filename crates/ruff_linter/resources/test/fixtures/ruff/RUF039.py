@@ -0,0 +1,28 @@
+async def bad():
+    return await f()  # RUF039
+
+
+async def bad_parenthesized():
+    return await (f())  # RUF039
+
+
+async def good_bound_first():
+    # Not flagged: the result is bound before being returned, so the await
+    # is load-bearing for exception propagation within this function.
+    x = await f()
+    return x
+
+
+async def good_not_terminal():
+    try:
+        return await f()  # OK (not the terminal statement)
+    except ValueError:
+        return None
+
+
+async def good_no_await():
+    return f()
+
+
+def good_sync():
+    return f()
@@ -0,0 +1,62 @@
+# RUF040 (fixable)
+if hasattr(obj, "name"):
+    x = obj.name
+else:
+    x = "default"
+
+
+def f(obj):
+    # RUF040 (fixable)
+    if hasattr(obj, "name"):
+        return obj.name
+    else:
+        return None
+
+
+# OK (multi-statement body)
+if hasattr(obj, "name"):
+    log("found")
+    x = obj.name
+else:
+    x = "default"
+
+# OK (targets differ)
+if hasattr(obj, "name"):
+    x = obj.name
+else:
+    y = "default"
+
+# OK (different attribute)
+if hasattr(obj, "name"):
+    x = obj.other
+else:
+    x = "default"
+
+# OK (no else branch)
+if hasattr(obj, "name"):
+    x = obj.name
+
+# OK (elif arm)
+if hasattr(obj, "name"):
+    x = obj.name
+elif fallback:
+    x = "default"
+
+# OK (receiver isn't a name)
+if hasattr(get_obj(), "name"):
+    x = get_obj().name
+else:
+    x = "default"
+
+# OK (non-literal attribute name)
+if hasattr(obj, attr_name):
+    x = obj.name
+else:
+    x = "default"
+
+# RUF040, but no fix: comments would be lost.
+if hasattr(obj, "name"):
+    x = obj.name
+else:
+    # Explanatory comment.
+    x = "default"
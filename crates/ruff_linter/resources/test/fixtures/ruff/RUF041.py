@@ -0,0 +1,17 @@
+y = [3, 1, 2]
+
+x = y.sort()  # RUF041 (fixable)
+x = y.sort(reverse=True)  # RUF041 (fixable)
+x = y.sort(key=abs, reverse=True)  # RUF041 (fixable)
+x = y.reverse()  # RUF041 (no fix)
+x = y.append(4)  # RUF041 (no fix)
+
+y.sort()  # OK (statement, result unused)
+x = sorted(y)  # OK
+x = y.count(1)  # OK (returns a value)
+x = unknown.sort()  # OK (receiver isn't a known list)
+
+
+def f(items: list[int]):
+    out = items.sort()  # RUF041 (fixable)
+    return out
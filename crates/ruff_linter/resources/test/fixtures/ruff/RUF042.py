@@ -0,0 +1,16 @@
+"a" + "b"  # RUF042
+f"a" + f"b"  # RUF042
+"a" + f"{x}"  # RUF042
+f"{x}" + "b"  # RUF042
+'a' + "b"  # RUF042 (differing quotes are fine implicitly)
+"a" + "b" + "c"  # RUF042 (inner concatenation)
+
+x + "b"  # OK
+"a" + x  # OK
+b"a" + b"b"  # OK (bytes)
+"a" + str(x)  # OK
+
+value = (
+    "multi-line concatenations are"
+    + "covered by ISC003"  # OK
+)
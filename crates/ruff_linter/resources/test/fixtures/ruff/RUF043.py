@@ -0,0 +1,25 @@
+int(5)  # RUF043
+str("x")  # RUF043
+float(1.0)  # RUF043
+str(f"{x}")  # RUF043
+
+count: int = compute()
+int(count)  # RUF043
+
+name = "spam"
+str(name)  # RUF043
+
+ratio = float(value)
+float(ratio)  # RUF043
+
+int(some_unknown)  # OK (type not known)
+int("5")  # OK (actual conversion)
+str(5)  # OK (actual conversion)
+float(1)  # OK (actual conversion)
+int(5, base=2)  # OK (keyword argument)
+int(x=5)  # OK (keyword argument)
+int(True)  # OK (bool is converted to an exact int)
+
+count = 1
+count = "one"
+int(count)  # OK (multiple bindings)
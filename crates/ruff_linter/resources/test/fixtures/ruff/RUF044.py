@@ -0,0 +1,17 @@
+x == 0.1  # RUF044
+x != 0.1  # RUF044
+0.1 == x  # RUF044
+x == -0.1  # RUF044
+0.1 + 0.2 == 0.3  # RUF044 (literal operand on the right)
+
+ratio: float = compute()
+ratio == other  # RUF044
+
+scale = 2.5
+scale != 1  # RUF044
+
+x == 0  # OK (int literal)
+x == y  # OK (types unknown)
+x < 0.1  # OK (ordering comparison)
+x <= 0.1  # OK (ordering comparison)
+x is y  # OK
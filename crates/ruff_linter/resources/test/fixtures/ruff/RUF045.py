@@ -0,0 +1,16 @@
+from pathlib import Path, PurePosixPath
+
+p = Path("archive")
+
+Path("a") / "/etc/passwd"  # RUF045
+p / "/abs"  # RUF045
+p / "subdir" / "/abs"  # RUF045
+PurePosixPath("a") / "/abs"  # RUF045
+p / "\\server\\share"  # RUF045 (Windows UNC prefix)
+p / "C:/Windows"  # RUF045 (Windows drive)
+
+p / "rel"  # OK
+p / "rel/nested"  # OK
+p / name  # OK (not a literal)
+"a" / "/abs"  # OK (left is not a path)
+unknown / "/abs"  # OK (left type unknown)
@@ -0,0 +1,57 @@
+def f():
+    return (x)  # RUF046
+
+
+def f():
+    return(x)  # RUF046
+
+
+def f():
+    return ((x))  # RUF046
+
+
+def f():
+    return (x + y)  # RUF046
+
+
+def f():
+    yield (x)  # RUF046
+
+
+def f():
+    value = yield (x)  # RUF046
+
+
+def f():
+    return x  # OK
+
+
+def f():
+    return (x, y)  # OK (tuple)
+
+
+def f():
+    return (x,)  # OK (tuple)
+
+
+def f():
+    return (v for v in x)  # OK (generator)
+
+
+def f():
+    return (x := 1)  # OK (named expression)
+
+
+def f():
+    return (yield x)  # OK (yield expression)
+
+
+def f():
+    return  # OK (no value)
+
+
+def f():
+    return (  # OK by default (multi-line)
+        some_long_name
+        + other_long_name
+    )
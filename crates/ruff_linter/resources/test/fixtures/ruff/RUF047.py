@@ -0,0 +1,12 @@
+import time
+
+start = time.time()
+elapsed = time.time() - start  # RUF047
+delta = start - time.time()  # RUF047
+jitter = time.time() - time.time()  # RUF047
+
+now = time.time()  # OK (timestamp)
+log(time.time())  # OK (timestamp)
+stamp = time.time() - epoch  # OK (other operand unknown)
+age = now - created_at  # OK (other operand unknown)
+offset = time.time() + skew  # OK (not a subtraction)
@@ -0,0 +1,7 @@
+f"{f'{f"{x}"}'}"  # RUF048 (three levels)
+f"outer {f'middle {f"inner {x}"} middle'} outer"  # RUF048 (three levels)
+f"{g(f'{f"{x}"}')}"  # RUF048 (nested inside a call)
+
+f"{x}"  # OK (no nesting)
+f"{f'{x}'}"  # OK at the default depth (two levels; flagged below 3.12)
+"plain" f"{x}"  # OK
@@ -0,0 +1,39 @@
+import asyncio
+
+
+def helper() -> int:
+    return 1
+
+
+def returns_none() -> None:
+    pass
+
+
+def unannotated():
+    return 1
+
+
+@functools.cache
+def decorated() -> int:
+    return 1
+
+
+async def produces() -> asyncio.Future:
+    ...
+
+
+async def main():
+    await 5  # RUF049
+    await "x"  # RUF049
+    await None  # RUF049
+    await [1, 2]  # RUF049
+    await (1, 2)  # RUF049
+    await f"{x}"  # RUF049
+    await helper()  # RUF049
+    await returns_none()  # RUF049
+
+    await unannotated()  # OK (return type unknown)
+    await some_unknown()  # OK (not defined in this file)
+    await decorated()  # OK (decorator may change the return)
+    await produces()  # OK (async function)
+    await asyncio.sleep(1)  # OK
@@ -0,0 +1,18 @@
+import sys
+
+print("error", file=sys.stderr)  # RUF050
+
+
+def report():
+    print("error", file=sys.stderr)  # RUF050
+
+
+print("hello")  # OK (stdout)
+print("log", file=log_file)  # OK (not stderr)
+sys.stderr.write("error\n")  # OK (not print)
+
+if __name__ == "__main__":
+    print("usage: prog FILE", file=sys.stderr)  # OK (script entry point)
+
+    def usage():
+        print("usage: prog FILE", file=sys.stderr)  # OK (under main guard)
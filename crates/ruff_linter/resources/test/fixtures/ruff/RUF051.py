@@ -0,0 +1,10 @@
+assert type(x) == int  # RUF051
+assert type(x) == MyClass  # RUF051
+assert type(x) == module.MyClass  # RUF051
+assert type(x) == int, "x must be an int"  # RUF051
+
+assert isinstance(x, int)  # OK
+assert type(x) is int  # OK (identity check is intentional)
+assert type(x) == type(y)  # OK (no isinstance equivalent)
+assert type(x) != int  # OK (inequality)
+assert type(x) == int == type(y)  # OK (chained comparison)
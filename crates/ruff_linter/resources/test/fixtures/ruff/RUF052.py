@@ -0,0 +1,9 @@
+__all__ = ["foo", "bar"]
+__all__ = ["baz"]  # RUF052
+__all__ += ["qux"]  # OK (augmented assignment extends)
+__all__.extend(["quux"])  # OK
+
+
+def f():
+    __all__ = ["not", "exports"]  # OK (not at module level)
+    __all__ = ["still", "local"]  # OK
@@ -0,0 +1,15 @@
+import json
+import random
+
+items = [1, 2, 3]
+
+random.choice(g for g in items)  # RUF053
+random.sample((g for g in items), 2)  # RUF053
+random.shuffle(g for g in items)  # RUF053
+json.dumps(g for g in items)  # RUF053
+
+sum(g for g in items)  # OK
+"".join(str(g) for g in items)  # OK
+random.choice(items)  # OK
+random.choice(list(g for g in items))  # OK
+json.dumps([g for g in items])  # OK
@@ -0,0 +1,14 @@
+some_bytes = b"data"
+some_str = "data"
+
+b"x" == "x"  # RUF054
+"x" != b"x"  # RUF054
+some_bytes == "data"  # RUF054
+some_bytes in "text"  # RUF054
+b"t" not in some_str  # RUF054
+
+"x" == "y"  # OK
+b"x" == b"y"  # OK
+some_bytes == b"data"  # OK
+some_bytes.decode() == "data"  # OK
+"t" in some_str  # OK
@@ -0,0 +1,15 @@
+import asyncio
+
+
+async def fetch(url): ...
+
+
+async def main():
+    await asyncio.gather(fetch("a"), fetch("b"))  # RUF055
+
+    await asyncio.gather(fetch("a"), fetch("b"), return_exceptions=True)  # OK
+    await asyncio.gather(fetch("a"), fetch("b"), return_exceptions=False)  # OK
+
+    # Not awaited here; the caller decides how to consume it.
+    task = asyncio.gather(fetch("a"), fetch("b"))  # OK
+    await task
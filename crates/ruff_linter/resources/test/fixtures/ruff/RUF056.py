@@ -0,0 +1,25 @@
+from external import SomeBase
+
+
+class Unslotted:
+    pass
+
+
+class Slotted:
+    __slots__ = ("x",)
+
+
+class Bad(Unslotted):
+    __slots__ = ("y",)  # RUF056
+
+
+class Good(Slotted):
+    __slots__ = ("y",)  # OK
+
+
+class Unknown(SomeBase):
+    __slots__ = ("y",)  # OK (base not defined in this file)
+
+
+class NoSlotsHere(Unslotted):  # OK (no __slots__ declared)
+    pass
@@ -0,0 +1,16 @@
+import functools
+import operator
+from functools import reduce
+
+numbers = [1, 2, 3]
+flags = [True, False]
+
+functools.reduce(operator.add, numbers)  # RUF057 (sum)
+reduce(operator.mul, numbers)  # RUF057 (math.prod)
+reduce(lambda a, b: a or b, flags)  # RUF057 (any)
+reduce(lambda a, b: a and b, flags)  # RUF057 (all)
+
+reduce(operator.add, numbers, 0)  # OK (initializer)
+reduce(lambda a, b: a + b * 2, numbers)  # OK (custom reducer)
+reduce(my_combine, numbers)  # OK
+reduce(lambda a, b: b or a, flags)  # OK (operand order differs)
@@ -0,0 +1,34 @@
+try:
+    import fast_json as json
+except ImportError:  # RUF058
+    pass
+
+try:
+    from lxml import etree
+except ImportError:  # RUF058
+    print("lxml missing")
+
+try:
+    import fast_json as json
+except ImportError:  # OK (fallback import)
+    import json
+
+try:
+    from fast_yaml import load
+except ImportError:  # OK (fallback assignment)
+    load = None
+
+try:
+    import fast_json as json
+except ImportError:  # OK (raises)
+    raise RuntimeError("fast_json is required")
+
+try:
+    import fast_json as json
+except ValueError:  # OK (not an ImportError handler)
+    pass
+
+try:
+    value = compute()
+except ImportError:  # OK (body is not an import)
+    pass
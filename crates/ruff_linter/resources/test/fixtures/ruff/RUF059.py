@@ -0,0 +1,17 @@
+lst = []
+items = [1, 2, 3]
+
+
+def f(x): ...
+
+
+[lst.append(x) for x in items]  # RUF059 (fixed to a for loop)
+
+[f(x) for x in items]  # RUF059 (result discarded)
+
+result = [f(x) for x in items]  # OK (list is used)
+print([f(x) for x in items])  # OK
+squares = [x * x for x in items]  # OK
+[x * x for x in items]  # OK (no call in the element)
+
+mapped = [lst.append(x) for x in items]  # RUF059 (mutator; no fix)
@@ -0,0 +1,12 @@
+items = [1, 2, 3]
+sep = ", "
+
+"".join([str(x) for x in items])  # RUF060
+sep.join([str(x) for x in items])  # RUF060
+f"{sep}".join([str(x) for x in items])  # RUF060
+
+"".join(str(x) for x in items)  # OK
+"".join((str(x) for x in items))  # OK
+"".join([])  # OK (not a comprehension)
+b"".join([bytes(x) for x in items])  # OK (bytes, not str)
+unknown.join([str(x) for x in items])  # OK (receiver type unknown)
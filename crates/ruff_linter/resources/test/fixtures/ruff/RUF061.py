@@ -0,0 +1,6 @@
+assert sorted(result) == sorted(expected)  # RUF061
+
+assert result == expected  # OK (order-sensitive)
+assert sorted(result) == expected  # OK (only one side sorted)
+assert sorted(result) != sorted(expected)  # OK (not an equality)
+assert sorted(result, key=str) == sorted(expected, key=str)  # OK (key may matter)
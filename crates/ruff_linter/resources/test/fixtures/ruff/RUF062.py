@@ -0,0 +1,26 @@
+class Parser:
+    version = 1
+
+    @staticmethod
+    def normalize(text):  # RUF062
+        return text.strip().lower()
+
+    @staticmethod
+    def make_default():  # OK (references the class)
+        return Parser()
+
+    @staticmethod
+    def current_version():  # OK (references a class attribute)
+        return Parser.version
+
+    @functools.cache
+    @staticmethod
+    def cached(text):  # OK (additional decorators)
+        return text
+
+    def parse(self, text):  # OK (not a staticmethod)
+        return self.normalize(text)
+
+    @staticmethod
+    def __call__(text):  # OK (dunder)
+        return text
@@ -0,0 +1,44 @@
+class Base:
+    def __init__(self):
+        self.resource = object()
+
+
+class Derived(Base):  # RUF063
+    def __init__(self):
+        self.extra = 1
+
+
+class Chained(Base):  # OK
+    def __init__(self):
+        super().__init__()
+        self.extra = 1
+
+
+class Explicit(Base):  # OK
+    def __init__(self):
+        Base.__init__(self)
+        self.extra = 1
+
+
+class NoInit(Base):  # OK: inherits `__init__`
+    pass
+
+
+class PlainBase:
+    pass
+
+
+class FromPlain(PlainBase):  # OK: base has no `__init__`
+    def __init__(self):
+        self.extra = 1
+
+
+class FromImported(SomeImportedBase):  # OK: base not resolvable in-file
+    def __init__(self):
+        self.extra = 1
+
+
+class Conditional(Base):  # OK: super call inside a branch still counts
+    def __init__(self, flag):
+        if flag:
+            super().__init__()
@@ -0,0 +1,16 @@
+text: str = "value"
+data: bytes = b"value"
+
+text.encode("utf-8")  # RUF064
+text.encode("utf8")  # RUF064
+text.encode("UTF-8")  # RUF064
+data.decode("utf-8")  # RUF064
+b"value".decode("utf-8")  # RUF064
+
+text.encode("latin-1")  # OK
+data.decode("latin-1")  # OK
+text.encode()  # OK
+data.decode()  # OK
+data.decode("utf-8", "ignore")  # OK: `errors` argument is significant
+"value".encode("utf-8")  # OK: string literals are handled by UP012
+unknown.encode("utf-8")  # OK: untyped receiver
@@ -0,0 +1,23 @@
+ready: bool = launch_ready()
+flag = True
+value = 1
+
+if ready == True:  # RUF065
+    pass
+if ready == False:  # RUF065
+    pass
+if ready != True:  # RUF065
+    pass
+if True == ready:  # RUF065
+    pass
+if (ready) == True:  # RUF065
+    pass
+if flag == True:  # RUF065: inferred from the `True` initializer
+    pass
+
+if value == True:  # OK: not a bool, left to E712
+    pass
+if unknown == True:  # OK: untyped, left to E712
+    pass
+if ready == ready:  # OK
+    pass
@@ -0,0 +1,19 @@
+for x in it:
+    del x  # RUF066
+
+for x in it:
+    del y  # OK
+
+for k, v in pairs:
+    del v  # RUF066
+
+for x in it:
+    for inner in others:
+        del x  # RUF066: deletes the outer loop's variable
+
+for x in it:
+    pass
+del x  # OK: after the loop
+
+while cond:
+    del x  # OK: `while` has no loop variable
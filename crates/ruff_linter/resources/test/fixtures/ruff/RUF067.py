@@ -0,0 +1,39 @@
+class SuppressAll:
+    def __exit__(self, exc_type, exc, tb):
+        self.close()
+        return True  # RUF067
+
+
+class SuppressAllInt:
+    def __exit__(self, exc_type, exc, tb):
+        return 1  # RUF067
+
+
+class AsyncSuppressAll:
+    async def __aexit__(self, exc_type, exc, tb):
+        return True  # RUF067
+
+
+class Propagates:
+    def __exit__(self, exc_type, exc, tb):
+        self.close()
+        return None  # OK
+
+
+class PropagatesFalse:
+    def __exit__(self, exc_type, exc, tb):
+        return False  # OK
+
+
+class Conditional:
+    def __exit__(self, exc_type, exc, tb):
+        return exc_type is KeyError  # OK: computed suppression is intentional
+
+
+class Implicit:
+    def __exit__(self, exc_type, exc, tb):
+        self.close()  # OK
+
+
+def __exit__(exc_type, exc, tb):
+    return True  # OK: not a method
@@ -0,0 +1,24 @@
+try:
+    import fast_parser as parser
+except ImportError:
+    import tolerant_reader as parser  # RUF068
+
+try:
+    import ujson as json
+except ImportError:
+    import json  # OK by default: idiomatic accelerator fallback
+
+try:
+    import json
+except ImportError:
+    import simplejson as json  # OK by default: canonical module first
+
+try:
+    import yaml
+except ImportError:
+    import yaml  # OK: same module
+
+try:
+    import numpy as np
+except ValueError:
+    import numpy_stub as np  # OK: not an import fallback handler
@@ -0,0 +1,47 @@
+import functools
+from functools import wraps
+
+
+def log_calls(func):  # RUF069
+    def wrapper(*args, **kwargs):
+        print(f"Calling {func.__name__}")
+        return func(*args, **kwargs)
+
+    return wrapper
+
+
+def retry(fn):  # RUF069: parameter name other than `func`
+    def inner(*args, **kwargs):
+        return fn(*args, **kwargs)
+
+    return inner
+
+
+def with_wraps(func):  # OK
+    @functools.wraps(func)
+    def wrapper(*args, **kwargs):
+        return func(*args, **kwargs)
+
+    return wrapper
+
+
+def with_bare_wraps(func):  # OK
+    @wraps(func)
+    def wrapper(*args, **kwargs):
+        return func(*args, **kwargs)
+
+    return wrapper
+
+
+def not_a_decorator(func):  # OK: the nested function is never returned
+    def helper():
+        return func
+
+    return None
+
+
+def returns_other(func, default):  # OK: more than one parameter
+    def wrapper(*args, **kwargs):
+        return func(*args, **kwargs)
+
+    return wrapper
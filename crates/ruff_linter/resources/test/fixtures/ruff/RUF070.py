@@ -0,0 +1,43 @@
+def read_lines(path):
+    with open(path) as file:  # RUF070
+        for line in file:
+            yield line
+
+
+def with_finally(resource):
+    resource.acquire()
+    try:
+        yield resource  # RUF070
+    finally:
+        resource.release()
+
+
+def yield_from_inside_with(path):
+    with open(path) as file:  # RUF070
+        yield from file
+
+
+def plain_generator(items):
+    for item in items:
+        yield item  # OK
+
+
+def try_without_finally():
+    try:
+        yield 1  # OK: no `finally` clause
+    except ValueError:
+        pass
+
+
+def nested_function_outside_with(path):
+    with open(path) as file:
+        def inner():
+            yield 1  # OK: `yield` belongs to `inner`, not the `with` block
+
+        return inner
+
+
+def eager_read(path):
+    with open(path) as file:
+        lines = file.readlines()
+    yield from lines  # OK: the file is already closed
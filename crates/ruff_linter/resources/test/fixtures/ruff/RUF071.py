@@ -0,0 +1,66 @@
+from enum import Enum
+from typing import Literal
+
+
+class Color(Enum):
+    RED = 1
+    GREEN = 2
+    BLUE = 3
+
+
+def incomplete(color: Color):
+    match color:  # RUF071: missing `Color.BLUE`
+        case Color.RED:
+            ...
+        case Color.GREEN:
+            ...
+
+
+def wildcard(color: Color):
+    match color:  # OK: wildcard case
+        case Color.RED:
+            ...
+        case _:
+            ...
+
+
+def complete(color: Color):
+    match color:  # OK: all members covered
+        case Color.RED | Color.GREEN:
+            ...
+        case Color.BLUE:
+            ...
+
+
+def capture(color: Color):
+    match color:  # OK: capture pattern is irrefutable
+        case Color.RED:
+            ...
+        case other:
+            ...
+
+
+class NotAnEnum:
+    RED = 1
+    GREEN = 2
+
+
+def not_an_enum(value):
+    match value:  # OK: not an enum
+        case NotAnEnum.RED:
+            ...
+
+
+mode: Literal["r", "w", "a"] = "r"
+
+match mode:  # RUF071: missing `"a"`
+    case "r":
+        ...
+    case "w":
+        ...
+
+match mode:  # OK: all literal values covered
+    case "r" | "w":
+        ...
+    case "a":
+        ...
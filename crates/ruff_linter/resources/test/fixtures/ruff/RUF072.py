@@ -0,0 +1,52 @@
+class EnterOnly:  # RUF072: missing `__exit__`
+    def __enter__(self):
+        return self
+
+
+class ExitOnly:  # RUF072: missing `__enter__`
+    def __exit__(self, exc_type, exc_value, traceback):
+        ...
+
+
+class AsyncEnterOnly:  # RUF072: missing `__aexit__`
+    async def __aenter__(self):
+        return self
+
+
+class Complete:  # OK
+    def __enter__(self):
+        return self
+
+    def __exit__(self, exc_type, exc_value, traceback):
+        ...
+
+
+class AsyncComplete:  # OK
+    async def __aenter__(self):
+        return self
+
+    async def __aexit__(self, exc_type, exc_value, traceback):
+        ...
+
+
+class Mixed:  # RUF072 twice: both pairs are incomplete
+    def __enter__(self):
+        return self
+
+    async def __aexit__(self, exc_type, exc_value, traceback):
+        ...
+
+
+class Inherits(Complete):  # OK: `__exit__` may come from the base class
+    def __enter__(self):
+        return self
+
+
+class ExplicitObject(object):  # RUF072: `object` provides neither method
+    def __enter__(self):
+        return self
+
+
+class Plain:  # OK: not a context manager at all
+    def method(self):
+        ...
@@ -0,0 +1,21 @@
+# RUF073
+_ = [0, 1, 2] == range(3)
+_ = range(3) == [0, 1, 2]
+_ = (0, 1, 2) != range(3)
+_ = 0.5 in range(10)
+_ = 2.0 not in range(10)
+
+# OK
+_ = 5 in range(10)
+_ = x in range(10)
+_ = range(3) == range(3)
+_ = list(range(3)) == [0, 1, 2]
+_ = [0, 1, 2] == other
+_ = [0.5] == list(range(10))
+
+
+def range(n):
+    return [n]
+
+
+_ = [0, 1, 2] == range(3)  # OK: `range` is shadowed
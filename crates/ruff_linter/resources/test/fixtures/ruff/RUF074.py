@@ -0,0 +1,16 @@
+import os
+from os import system
+
+os.system("rm x")  # RUF074
+system("make all")  # RUF074
+
+rc = os.system("x")  # OK: return code is captured
+if os.system("x"):  # OK: return code is checked
+    pass
+print(os.system("x"))  # OK: return code is used
+
+
+def system(cmd): ...
+
+
+system("rm x")  # OK: `os.system` is shadowed
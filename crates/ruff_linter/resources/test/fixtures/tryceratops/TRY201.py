@@ -72,3 +72,28 @@ def bad_that_needs_recursion_2():
 
             def foo():
                 raise e
+
+
+def good_reassigned():
+    try:
+        process()
+    except MyException as e:
+        e = wrap(e)
+        raise e
+
+
+def good_reassigned_for():
+    try:
+        process()
+    except MyException as e:
+        for e in errors():
+            pass
+        raise e
+
+
+def bad_reassigned_other_name():
+    try:
+        process()
+    except MyException as e:
+        other = wrap(e)
+        raise e
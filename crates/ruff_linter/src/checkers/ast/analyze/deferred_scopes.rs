@@ -109,12 +109,30 @@ pub(crate) fn deferred_scopes(checker: &mut Checker) {
                         .map(|id| checker.semantic.reference(*id))
                         .all(ResolvedReference::is_load)
                     {
-                        diagnostics.push(Diagnostic::new(
+                        let mut diagnostic = Diagnostic::new(
                             pylint::rules::GlobalVariableNotAssigned {
                                 name: (*name).to_string(),
                             },
                             binding.range(),
-                        ));
+                        );
+                        // Remove the `global` statement, but only if it declares
+                        // no other names.
+                        if let Some(stmt) = binding.statement(&checker.semantic) {
+                            if stmt
+                                .as_global_stmt()
+                                .is_some_and(|global| global.names.len() == 1)
+                            {
+                                diagnostic.set_fix(Fix::safe_edit(fix::edits::delete_stmt(
+                                    stmt,
+                                    binding
+                                        .source
+                                        .and_then(|id| checker.semantic.parent_statement(id)),
+                                    checker.locator(),
+                                    checker.indexer(),
+                                )));
+                            }
+                        }
+                        diagnostics.push(diagnostic);
                     }
                 }
             }
@@ -16,7 +16,8 @@ use crate::rules::{
     flake8_future_annotations, flake8_gettext, flake8_implicit_str_concat, flake8_logging,
     flake8_logging_format, flake8_pie, flake8_print, flake8_pyi, flake8_pytest_style, flake8_self,
     flake8_simplify, flake8_tidy_imports, flake8_trio, flake8_type_checking, flake8_use_pathlib,
-    flynt, numpy, pandas_vet, pep8_naming, pycodestyle, pyflakes, pylint, pyupgrade, refurb, ruff,
+    flynt, numpy, pandas_vet, pep8_naming, perflint, pycodestyle, pyflakes, pylint, pyupgrade,
+    refurb, ruff,
 };
 use crate::settings::types::PythonVersion;
 
@@ -568,6 +569,9 @@ pub(crate) fn expression(expr: &Expr, checker: &mut Checker) {
             if checker.enabled(Rule::UnnecessaryDictKwargs) {
                 flake8_pie::rules::unnecessary_dict_kwargs(checker, call);
             }
+            if checker.enabled(Rule::UnnecessaryDictComprehensionWrapper) {
+                flake8_pie::rules::unnecessary_dict_comprehension_wrapper(checker, call);
+            }
             if checker.enabled(Rule::UnnecessaryRangeStart) {
                 flake8_pie::rules::unnecessary_range_start(checker, call);
             }
@@ -963,6 +967,9 @@ pub(crate) fn expression(expr: &Expr, checker: &mut Checker) {
             if checker.enabled(Rule::UnnecessaryDunderCall) {
                 pylint::rules::unnecessary_dunder_call(checker, call);
             }
+            if checker.enabled(Rule::LenTest) {
+                pylint::rules::len_test(checker, call);
+            }
             if checker.enabled(Rule::SslWithNoVersion) {
                 flake8_bandit::rules::ssl_with_no_version(checker, call);
             }
@@ -978,6 +985,36 @@ pub(crate) fn expression(expr: &Expr, checker: &mut Checker) {
             if checker.enabled(Rule::DefaultFactoryKwarg) {
                 ruff::rules::default_factory_kwarg(checker, call);
             }
+            if checker.enabled(Rule::EnvVarTruthiness) {
+                ruff::rules::env_var_truthiness(checker, call);
+            }
+            if checker.enabled(Rule::SortedConstant) {
+                perflint::rules::sorted_constant(checker, call);
+            }
+            if checker.enabled(Rule::UnnecessaryCopy) {
+                perflint::rules::unnecessary_copy(checker, call);
+            }
+            if checker.enabled(Rule::RedundantTypeConversion) {
+                ruff::rules::redundant_type_conversion(checker, call);
+            }
+            if checker.enabled(Rule::PrintToStderr) {
+                ruff::rules::print_to_stderr(checker, call);
+            }
+            if checker.enabled(Rule::GeneratorWhereListNeeded) {
+                ruff::rules::generator_where_list_needed(checker, call);
+            }
+            if checker.enabled(Rule::AsyncioGatherSwallowsExceptions) {
+                ruff::rules::asyncio_gather_swallows_exceptions(checker, call);
+            }
+            if checker.enabled(Rule::ReducibleReduce) {
+                ruff::rules::reducible_reduce(checker, call);
+            }
+            if checker.enabled(Rule::UnnecessaryListInJoin) {
+                ruff::rules::unnecessary_list_in_join(checker, call);
+            }
+            if checker.enabled(Rule::RedundantUtf8Codec) {
+                ruff::rules::redundant_utf8_codec(checker, call);
+            }
             if checker.enabled(Rule::UnnecessaryIterableAllocationForFirstElement) {
                 ruff::rules::unnecessary_iterable_allocation_for_first_element(checker, expr);
             }
@@ -995,19 +1032,28 @@ pub(crate) fn expression(expr: &Expr, checker: &mut Checker) {
             if checker.enabled(Rule::UnnecessarySpread) {
                 flake8_pie::rules::unnecessary_spread(checker, dict);
             }
+            if checker.enabled(Rule::DictMergeWithPipe) {
+                pyupgrade::rules::dict_merge_with_pipe(checker, dict);
+            }
         }
         Expr::Set(set) => {
             if checker.enabled(Rule::DuplicateValue) {
                 flake8_bugbear::rules::duplicate_value(checker, set);
             }
         }
-        Expr::Yield(_) => {
+        Expr::Yield(yield_expr) => {
             if checker.enabled(Rule::YieldOutsideFunction) {
                 pyflakes::rules::yield_outside_function(checker, expr);
             }
             if checker.enabled(Rule::YieldInInit) {
                 pylint::rules::yield_in_init(checker, expr);
             }
+            if checker.enabled(Rule::RedundantParenthesesOnReturn) {
+                ruff::rules::redundant_parentheses_on_yield(checker, yield_expr);
+            }
+            if checker.enabled(Rule::YieldInsideContextManager) {
+                ruff::rules::yield_inside_context_manager(checker, expr);
+            }
         }
         Expr::YieldFrom(yield_from) => {
             if checker.enabled(Rule::YieldOutsideFunction) {
@@ -1019,14 +1065,20 @@ pub(crate) fn expression(expr: &Expr, checker: &mut Checker) {
             if checker.enabled(Rule::YieldFromInAsyncFunction) {
                 pylint::rules::yield_from_in_async_function(checker, yield_from);
             }
+            if checker.enabled(Rule::YieldInsideContextManager) {
+                ruff::rules::yield_inside_context_manager(checker, expr);
+            }
         }
-        Expr::Await(_) => {
+        Expr::Await(await_expr) => {
             if checker.enabled(Rule::YieldOutsideFunction) {
                 pyflakes::rules::yield_outside_function(checker, expr);
             }
             if checker.enabled(Rule::AwaitOutsideAsync) {
                 pylint::rules::await_outside_async(checker, expr);
             }
+            if checker.enabled(Rule::AwaitNonAwaitable) {
+                ruff::rules::await_non_awaitable(checker, await_expr);
+            }
         }
         Expr::FString(f_string_expr @ ast::ExprFString { value, .. }) => {
             if checker.enabled(Rule::FStringMissingPlaceholders) {
@@ -1037,6 +1089,14 @@ pub(crate) fn expression(expr: &Expr, checker: &mut Checker) {
                     ruff::rules::explicit_f_string_type_conversion(checker, f_string);
                 }
             }
+            if checker.enabled(Rule::FStringDebugSpecifier) {
+                for f_string in value.f_strings() {
+                    ruff::rules::fstring_debug_specifier(checker, f_string);
+                }
+            }
+            if checker.enabled(Rule::DeeplyNestedFString) {
+                ruff::rules::deeply_nested_fstring(checker, expr);
+            }
             if checker.enabled(Rule::HardcodedSQLExpression) {
                 flake8_bandit::rules::hardcoded_sql_expression(checker, expr);
             }
@@ -1087,15 +1147,12 @@ pub(crate) fn expression(expr: &Expr, checker: &mut Checker) {
                     match pyflakes::cformat::CFormatSummary::try_from(value.to_str()) {
                         Err(CFormatError {
                             typ: CFormatErrorType::UnsupportedFormatChar(c),
-                            ..
+                            index,
                         }) => {
                             if checker.enabled(Rule::PercentFormatUnsupportedFormatCharacter) {
-                                checker.diagnostics.push(Diagnostic::new(
-                                    pyflakes::rules::PercentFormatUnsupportedFormatCharacter {
-                                        char: c,
-                                    },
-                                    location,
-                                ));
+                                pyflakes::rules::percent_format_unsupported_format_character(
+                                    checker, left, value, c, index, location,
+                                );
                             }
                         }
                         Err(e) => {
@@ -1171,6 +1228,9 @@ pub(crate) fn expression(expr: &Expr, checker: &mut Checker) {
                     checker.diagnostics.push(diagnostic);
                 }
             }
+            if checker.enabled(Rule::ImplicitStringConcatenationPreferred) {
+                ruff::rules::implicit_string_concatenation_preferred(checker, expr);
+            }
             if checker.enabled(Rule::CollectionLiteralConcatenation) {
                 ruff::rules::collection_literal_concatenation(checker, expr);
             }
@@ -1178,6 +1238,24 @@ pub(crate) fn expression(expr: &Expr, checker: &mut Checker) {
                 flake8_bandit::rules::hardcoded_sql_expression(checker, expr);
             }
         }
+        Expr::BinOp(
+            bin_op @ ast::ExprBinOp {
+                op: Operator::Sub, ..
+            },
+        ) => {
+            if checker.enabled(Rule::WallClockElapsedTime) {
+                ruff::rules::wall_clock_elapsed_time(checker, bin_op);
+            }
+        }
+        Expr::BinOp(
+            bin_op @ ast::ExprBinOp {
+                op: Operator::Div, ..
+            },
+        ) => {
+            if checker.enabled(Rule::PathJoinWithAbsolute) {
+                ruff::rules::path_join_with_absolute(checker, bin_op);
+            }
+        }
         Expr::BinOp(ast::ExprBinOp {
             op: Operator::BitOr,
             ..
@@ -1296,7 +1374,10 @@ pub(crate) fn expression(expr: &Expr, checker: &mut Checker) {
                 pylint::rules::magic_value_comparison(checker, left, comparators);
             }
             if checker.enabled(Rule::NanComparison) {
-                pylint::rules::nan_comparison(checker, left, comparators);
+                pylint::rules::nan_comparison(checker, compare);
+            }
+            if checker.enabled(Rule::ComparisonWithRange) {
+                ruff::rules::comparison_with_range(checker, compare);
             }
             if checker.enabled(Rule::InDictKeys) {
                 flake8_simplify::rules::key_in_dict_compare(checker, compare);
@@ -1319,6 +1400,15 @@ pub(crate) fn expression(expr: &Expr, checker: &mut Checker) {
             if checker.enabled(Rule::SingleItemMembershipTest) {
                 refurb::rules::single_item_membership_test(checker, expr, left, ops, comparators);
             }
+            if checker.enabled(Rule::FloatEqualityComparison) {
+                ruff::rules::float_equality_comparison(checker, compare);
+            }
+            if checker.enabled(Rule::BytesStrComparison) {
+                ruff::rules::bytes_str_comparison(checker, compare);
+            }
+            if checker.enabled(Rule::RedundantBooleanComparison) {
+                ruff::rules::redundant_boolean_comparison(checker, compare);
+            }
         }
         Expr::NumberLiteral(number_literal @ ast::ExprNumberLiteral { .. }) => {
             if checker.source_type.is_stub() && checker.enabled(Rule::NumericLiteralTooLong) {
@@ -1403,6 +1493,9 @@ pub(crate) fn expression(expr: &Expr, checker: &mut Checker) {
             if checker.enabled(Rule::ReimplementedStarmap) {
                 refurb::rules::reimplemented_starmap(checker, &comp.into());
             }
+            if checker.enabled(Rule::SideEffectInComprehension) {
+                ruff::rules::side_effect_in_comprehension(checker, comp);
+            }
         }
         Expr::SetComp(
             comp @ ast::ExprSetComp {
@@ -12,8 +12,9 @@ use crate::rules::{
     airflow, flake8_bandit, flake8_boolean_trap, flake8_bugbear, flake8_builtins, flake8_debugger,
     flake8_django, flake8_errmsg, flake8_import_conventions, flake8_pie, flake8_pyi,
     flake8_pytest_style, flake8_raise, flake8_return, flake8_simplify, flake8_slots,
-    flake8_tidy_imports, flake8_trio, flake8_type_checking, mccabe, pandas_vet, pep8_naming,
-    perflint, pycodestyle, pyflakes, pygrep_hooks, pylint, pyupgrade, refurb, ruff, tryceratops,
+    flake8_tidy_imports, flake8_trio, flake8_type_checking, flake8_use_pathlib, mccabe, pandas_vet,
+    pep8_naming, perflint, pycodestyle, pyflakes, pygrep_hooks, pylint, pyupgrade, refurb, ruff,
+    tryceratops,
 };
 use crate::settings::types::PythonVersion;
 
@@ -97,6 +98,12 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::InvalidBoolReturnType) {
                 pylint::rules::invalid_bool_return(checker, function_def);
             }
+            if checker.enabled(Rule::ExitReturnsTruthy) {
+                ruff::rules::exit_returns_truthy(checker, function_def);
+            }
+            if checker.enabled(Rule::DecoratorMissingWraps) {
+                ruff::rules::decorator_missing_wraps(checker, function_def);
+            }
             if checker.enabled(Rule::InvalidLengthReturnType) {
                 pylint::rules::invalid_length_return(checker, function_def);
             }
@@ -363,14 +370,32 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::UnusedAsync) {
                 ruff::rules::unused_async(checker, function_def);
             }
+            if checker.enabled(Rule::MisannotatedGenerator) {
+                ruff::rules::misannotated_generator(checker, function_def);
+            }
+            if checker.enabled(Rule::DuplicateDecorator) {
+                ruff::rules::duplicate_decorator(checker, decorator_list);
+            }
+            if checker.enabled(Rule::InconsistentReturns) {
+                pylint::rules::inconsistent_returns(checker, function_def);
+            }
+            if checker.enabled(Rule::WrongDecoratorOrder) {
+                ruff::rules::wrong_decorator_order(checker, function_def);
+            }
+            if checker.enabled(Rule::UnnecessaryReturnAwait) {
+                ruff::rules::unnecessary_return_await(checker, function_def);
+            }
         }
-        Stmt::Return(_) => {
+        Stmt::Return(return_stmt) => {
             if checker.enabled(Rule::ReturnOutsideFunction) {
                 pyflakes::rules::return_outside_function(checker, stmt);
             }
             if checker.enabled(Rule::ReturnInInit) {
                 pylint::rules::return_in_init(checker, stmt);
             }
+            if checker.enabled(Rule::RedundantParenthesesOnReturn) {
+                ruff::rules::redundant_parentheses_on_return(checker, return_stmt);
+            }
         }
         Stmt::ClassDef(
             class_def @ ast::StmtClassDef {
@@ -517,6 +542,18 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::NoSlotsInNamedtupleSubclass) {
                 flake8_slots::rules::no_slots_in_namedtuple_subclass(checker, stmt, class_def);
             }
+            if checker.enabled(Rule::SlotsWithoutAllBasesSlotted) {
+                ruff::rules::slots_without_all_bases_slotted(checker, class_def);
+            }
+            if checker.enabled(Rule::StaticMethodCouldBeFunction) {
+                ruff::rules::staticmethod_could_be_function(checker, class_def);
+            }
+            if checker.enabled(Rule::MissingSuperInitCall) {
+                ruff::rules::missing_super_init_call(checker, class_def);
+            }
+            if checker.enabled(Rule::IncompleteContextManagerProtocol) {
+                ruff::rules::incomplete_context_manager_protocol(checker, class_def);
+            }
             if checker.enabled(Rule::NonSlotAssignment) {
                 pylint::rules::non_slot_assignment(checker, class_def);
             }
@@ -526,6 +563,9 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::MetaClassABCMeta) {
                 refurb::rules::metaclass_abcmeta(checker, class_def);
             }
+            if checker.enabled(Rule::DuplicateDecorator) {
+                ruff::rules::duplicate_decorator(checker, decorator_list);
+            }
         }
         Stmt::Import(ast::StmtImport { names, range: _ }) => {
             if checker.enabled(Rule::MultipleImportsOnOneLine) {
@@ -1149,6 +1189,9 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::IfStmtMinMax) {
                 pylint::rules::if_stmt_min_max(checker, if_);
             }
+            if checker.enabled(Rule::HasattrThenGetattr) {
+                ruff::rules::hasattr_then_getattr(checker, if_);
+            }
             if checker.source_type.is_stub() {
                 if checker.any_enabled(&[
                     Rule::UnrecognizedVersionInfoCheck,
@@ -1195,11 +1238,13 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
                 }
             }
         }
-        Stmt::Assert(ast::StmtAssert {
-            test,
-            msg,
-            range: _,
-        }) => {
+        Stmt::Assert(
+            assert_stmt @ ast::StmtAssert {
+                test,
+                msg,
+                range: _,
+            },
+        ) => {
             if !checker.semantic.in_type_checking_block() {
                 if checker.enabled(Rule::Assert) {
                     checker
@@ -1210,6 +1255,12 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::AssertTuple) {
                 pyflakes::rules::assert_tuple(checker, stmt, test);
             }
+            if checker.enabled(Rule::AssertTypeEquality) {
+                ruff::rules::assert_type_equality(checker, assert_stmt);
+            }
+            if checker.enabled(Rule::AssertDictSetEquality) {
+                ruff::rules::assert_dict_set_equality(checker, assert_stmt);
+            }
             if checker.enabled(Rule::AssertFalse) {
                 flake8_bugbear::rules::assert_false(checker, stmt, test, msg.as_deref());
             }
@@ -1230,6 +1281,12 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::InvalidMockAccess) {
                 pygrep_hooks::rules::non_existent_mock_method(checker, test);
             }
+            if checker.enabled(Rule::AssertMessageSideEffect) {
+                ruff::rules::assert_message_side_effect(checker, msg.as_deref());
+            }
+            if checker.enabled(Rule::WalrusInAssertMessage) {
+                ruff::rules::walrus_in_assert_message(checker, assert_stmt);
+            }
         }
         Stmt::With(with_stmt @ ast::StmtWith { items, body, .. }) => {
             if checker.enabled(Rule::TooManyNestedBlocks) {
@@ -1263,6 +1320,9 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::TrioTimeoutWithoutAwait) {
                 flake8_trio::rules::timeout_without_await(checker, with_stmt, items);
             }
+            if checker.enabled(Rule::MultipleWithItemsNeedsParens) {
+                ruff::rules::multiple_with_items_needs_parens(checker, with_stmt);
+            }
         }
         Stmt::While(while_stmt @ ast::StmtWhile { body, orelse, .. }) => {
             if checker.enabled(Rule::TooManyNestedBlocks) {
@@ -1331,6 +1391,9 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::ManualListCopy) {
                 perflint::rules::manual_list_copy(checker, for_stmt);
             }
+            if checker.enabled(Rule::IterateOverRangeLen) {
+                perflint::rules::iterate_over_range_len(checker, for_stmt);
+            }
             if checker.enabled(Rule::ManualDictComprehension) {
                 perflint::rules::manual_dict_comprehension(checker, target, body);
             }
@@ -1364,13 +1427,15 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
                 }
             }
         }
-        Stmt::Try(ast::StmtTry {
-            body,
-            handlers,
-            orelse,
-            finalbody,
-            ..
-        }) => {
+        Stmt::Try(
+            try_stmt @ ast::StmtTry {
+                body,
+                handlers,
+                orelse,
+                finalbody,
+                ..
+            },
+        ) => {
             if checker.enabled(Rule::TooManyNestedBlocks) {
                 pylint::rules::too_many_nested_blocks(checker, stmt);
             }
@@ -1437,6 +1502,12 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::ErrorInsteadOfException) {
                 tryceratops::rules::error_instead_of_exception(checker, handlers);
             }
+            if checker.enabled(Rule::ConditionalImportWithoutFallback) {
+                ruff::rules::conditional_import_without_fallback(checker, try_stmt);
+            }
+            if checker.enabled(Rule::ConflictingConditionalImport) {
+                ruff::rules::conflicting_conditional_import(checker, try_stmt);
+            }
         }
         Stmt::Assign(assign @ ast::StmtAssign { targets, value, .. }) => {
             if checker.enabled(Rule::SelfOrClsAssignment) {
@@ -1522,6 +1593,15 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::UnsortedDunderSlots) {
                 ruff::rules::sort_dunder_slots_assign(checker, assign);
             }
+            if checker.enabled(Rule::AssignmentFromSortInPlace) {
+                ruff::rules::assignment_from_sort_in_place(checker, assign);
+            }
+            if checker.enabled(Rule::RedefinedDunderAll) {
+                ruff::rules::redefined_dunder_all(checker, assign);
+            }
+            if checker.enabled(Rule::OsPathJoinInLoop) {
+                flake8_use_pathlib::rules::os_path_join_in_loop(checker, assign);
+            }
             if checker.source_type.is_stub() {
                 if checker.any_enabled(&[
                     Rule::UnprefixedTypeParam,
@@ -1654,6 +1734,9 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::DeleteFullSlice) {
                 refurb::rules::delete_full_slice(checker, delete);
             }
+            if checker.enabled(Rule::DeleteLoopVariable) {
+                ruff::rules::delete_loop_variable(checker, delete);
+            }
         }
         Stmt::Expr(expr @ ast::StmtExpr { value, range: _ }) => {
             if checker.enabled(Rule::UselessComparison) {
@@ -1681,6 +1764,14 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::UselessExceptionStatement) {
                 pylint::rules::useless_exception_statement(checker, expr);
             }
+            if checker.enabled(Rule::IgnoredProcessReturnCode) {
+                ruff::rules::ignored_process_return_code(checker, value);
+            }
+        }
+        Stmt::Match(match_stmt) => {
+            if checker.enabled(Rule::NonExhaustiveMatch) {
+                ruff::rules::non_exhaustive_match(checker, match_stmt);
+            }
         }
         _ => {}
     }
@@ -355,6 +355,11 @@ impl<'a> Checker<'a> {
         self.package
     }
 
+    /// The [`Module`] representing the current file.
+    pub(crate) const fn module(&self) -> &Module<'a> {
+        &self.module
+    }
+
     /// The [`CellOffsets`] for the current file, if it's a Jupyter notebook.
     pub(crate) const fn cell_offsets(&self) -> Option<&'a CellOffsets> {
         self.cell_offsets
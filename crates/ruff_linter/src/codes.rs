@@ -225,6 +225,7 @@ pub fn code_to_rule(linter: Linter, code: &str) -> Option<(RuleGroup, Rule)> {
         (Pylint, "C0208") => (RuleGroup::Stable, rules::pylint::rules::IterationOverSet),
         (Pylint, "C0414") => (RuleGroup::Stable, rules::pylint::rules::UselessImportAlias),
         (Pylint, "C0415") => (RuleGroup::Preview, rules::pylint::rules::ImportOutsideTopLevel),
+        (Pylint, "C1802") => (RuleGroup::Preview, rules::pylint::rules::LenTest),
         #[allow(deprecated)]
         (Pylint, "C1901") => (RuleGroup::Nursery, rules::pylint::rules::CompareToEmptyString),
         (Pylint, "C2401") => (RuleGroup::Preview, rules::pylint::rules::NonAsciiName),
@@ -288,6 +289,7 @@ pub fn code_to_rule(linter: Linter, code: &str) -> Option<(RuleGroup, Rule)> {
         (Pylint, "R1702") => (RuleGroup::Preview, rules::pylint::rules::TooManyNestedBlocks),
         (Pylint, "R1704") => (RuleGroup::Preview, rules::pylint::rules::RedefinedArgumentFromLocal),
         (Pylint, "R1706") => (RuleGroup::Removed, rules::pylint::rules::AndOrTernary),
+        (Pylint, "R1710") => (RuleGroup::Preview, rules::pylint::rules::InconsistentReturns),
         (Pylint, "R1711") => (RuleGroup::Stable, rules::pylint::rules::UselessReturn),
         (Pylint, "R1714") => (RuleGroup::Stable, rules::pylint::rules::RepeatedEqualityComparison),
         (Pylint, "R1722") => (RuleGroup::Stable, rules::pylint::rules::SysExitAlias),
@@ -556,6 +558,7 @@ pub fn code_to_rule(linter: Linter, code: &str) -> Option<(RuleGroup, Rule)> {
         (Pyupgrade, "040") => (RuleGroup::Stable, rules::pyupgrade::rules::NonPEP695TypeAlias),
         (Pyupgrade, "041") => (RuleGroup::Stable, rules::pyupgrade::rules::TimeoutErrorAlias),
         (Pyupgrade, "042") => (RuleGroup::Preview, rules::pyupgrade::rules::ReplaceStrEnum),
+        (Pyupgrade, "043") => (RuleGroup::Preview, rules::pyupgrade::rules::DictMergeWithPipe),
 
         // pydocstyle
         (Pydocstyle, "100") => (RuleGroup::Stable, rules::pydocstyle::rules::UndocumentedPublicModule),
@@ -849,6 +852,7 @@ pub fn code_to_rule(linter: Linter, code: &str) -> Option<(RuleGroup, Rule)> {
         (Flake8Pie, "807") => (RuleGroup::Stable, rules::flake8_pie::rules::ReimplementedContainerBuiltin),
         (Flake8Pie, "808") => (RuleGroup::Stable, rules::flake8_pie::rules::UnnecessaryRangeStart),
         (Flake8Pie, "810") => (RuleGroup::Stable, rules::flake8_pie::rules::MultipleStartsEndsWith),
+        (Flake8Pie, "811") => (RuleGroup::Preview, rules::flake8_pie::rules::UnnecessaryDictComprehensionWrapper),
 
         // flake8-commas
         (Flake8Commas, "812") => (RuleGroup::Stable, rules::flake8_commas::rules::MissingTrailingComma),
@@ -919,6 +923,8 @@ pub fn code_to_rule(linter: Linter, code: &str) -> Option<(RuleGroup, Rule)> {
         (Flake8UsePathlib, "205") => (RuleGroup::Stable, rules::flake8_use_pathlib::rules::OsPathGetctime),
         (Flake8UsePathlib, "206") => (RuleGroup::Stable, rules::flake8_use_pathlib::rules::OsSepSplit),
         (Flake8UsePathlib, "207") => (RuleGroup::Stable, rules::flake8_use_pathlib::rules::Glob),
+        (Flake8UsePathlib, "208") => (RuleGroup::Preview, rules::flake8_use_pathlib::rules::OsPathJoinInLoop),
+        (Flake8UsePathlib, "209") => (RuleGroup::Preview, rules::flake8_use_pathlib::violations::OsPathRelpath),
 
         // flake8-logging-format
         (Flake8LoggingFormat, "001") => (RuleGroup::Stable, rules::flake8_logging_format::violations::LoggingStringFormat),
@@ -970,6 +976,51 @@ pub fn code_to_rule(linter: Linter, code: &str) -> Option<(RuleGroup, Rule)> {
         (Ruff, "027") => (RuleGroup::Preview, rules::ruff::rules::MissingFStringSyntax),
         (Ruff, "028") => (RuleGroup::Preview, rules::ruff::rules::InvalidFormatterSuppressionComment),
         (Ruff, "029") => (RuleGroup::Preview, rules::ruff::rules::UnusedAsync),
+        (Ruff, "030") => (RuleGroup::Preview, rules::ruff::rules::MisannotatedGenerator),
+        (Ruff, "031") => (RuleGroup::Preview, rules::ruff::rules::EnvVarTruthiness),
+        (Ruff, "032") => (RuleGroup::Preview, rules::ruff::rules::AssertMessageSideEffect),
+        (Ruff, "033") => (RuleGroup::Preview, rules::ruff::rules::DuplicateDecorator),
+        (Ruff, "034") => (RuleGroup::Preview, rules::ruff::rules::MultipleWithItemsNeedsParens),
+        (Ruff, "035") => (RuleGroup::Preview, rules::ruff::rules::WalrusInAssertMessage),
+        (Ruff, "036") => (RuleGroup::Preview, rules::ruff::rules::InconsistentOptionalStyle),
+        (Ruff, "037") => (RuleGroup::Preview, rules::ruff::rules::FStringDebugSpecifier),
+        (Ruff, "038") => (RuleGroup::Preview, rules::ruff::rules::WrongDecoratorOrder),
+        (Ruff, "039") => (RuleGroup::Preview, rules::ruff::rules::UnnecessaryReturnAwait),
+        (Ruff, "040") => (RuleGroup::Preview, rules::ruff::rules::HasattrThenGetattr),
+        (Ruff, "041") => (RuleGroup::Preview, rules::ruff::rules::AssignmentFromSortInPlace),
+        (Ruff, "042") => (RuleGroup::Preview, rules::ruff::rules::ImplicitStringConcatenationPreferred),
+        (Ruff, "043") => (RuleGroup::Preview, rules::ruff::rules::RedundantTypeConversion),
+        (Ruff, "044") => (RuleGroup::Preview, rules::ruff::rules::FloatEqualityComparison),
+        (Ruff, "045") => (RuleGroup::Preview, rules::ruff::rules::PathJoinWithAbsolute),
+        (Ruff, "046") => (RuleGroup::Preview, rules::ruff::rules::RedundantParenthesesOnReturn),
+        (Ruff, "047") => (RuleGroup::Preview, rules::ruff::rules::WallClockElapsedTime),
+        (Ruff, "048") => (RuleGroup::Preview, rules::ruff::rules::DeeplyNestedFString),
+        (Ruff, "049") => (RuleGroup::Preview, rules::ruff::rules::AwaitNonAwaitable),
+        (Ruff, "050") => (RuleGroup::Preview, rules::ruff::rules::PrintToStderr),
+        (Ruff, "051") => (RuleGroup::Preview, rules::ruff::rules::AssertTypeEquality),
+        (Ruff, "052") => (RuleGroup::Preview, rules::ruff::rules::RedefinedDunderAll),
+        (Ruff, "053") => (RuleGroup::Preview, rules::ruff::rules::GeneratorWhereListNeeded),
+        (Ruff, "054") => (RuleGroup::Preview, rules::ruff::rules::BytesStrComparison),
+        (Ruff, "055") => (RuleGroup::Preview, rules::ruff::rules::AsyncioGatherSwallowsExceptions),
+        (Ruff, "056") => (RuleGroup::Preview, rules::ruff::rules::SlotsWithoutAllBasesSlotted),
+        (Ruff, "057") => (RuleGroup::Preview, rules::ruff::rules::ReducibleReduce),
+        (Ruff, "058") => (RuleGroup::Preview, rules::ruff::rules::ConditionalImportWithoutFallback),
+        (Ruff, "059") => (RuleGroup::Preview, rules::ruff::rules::SideEffectInComprehension),
+        (Ruff, "060") => (RuleGroup::Preview, rules::ruff::rules::UnnecessaryListInJoin),
+        (Ruff, "061") => (RuleGroup::Preview, rules::ruff::rules::AssertDictSetEquality),
+        (Ruff, "062") => (RuleGroup::Preview, rules::ruff::rules::StaticMethodCouldBeFunction),
+        (Ruff, "063") => (RuleGroup::Preview, rules::ruff::rules::MissingSuperInitCall),
+        (Ruff, "064") => (RuleGroup::Preview, rules::ruff::rules::RedundantUtf8Codec),
+        (Ruff, "065") => (RuleGroup::Preview, rules::ruff::rules::RedundantBooleanComparison),
+        (Ruff, "066") => (RuleGroup::Preview, rules::ruff::rules::DeleteLoopVariable),
+        (Ruff, "067") => (RuleGroup::Preview, rules::ruff::rules::ExitReturnsTruthy),
+        (Ruff, "068") => (RuleGroup::Preview, rules::ruff::rules::ConflictingConditionalImport),
+        (Ruff, "069") => (RuleGroup::Preview, rules::ruff::rules::DecoratorMissingWraps),
+        (Ruff, "070") => (RuleGroup::Preview, rules::ruff::rules::YieldInsideContextManager),
+        (Ruff, "071") => (RuleGroup::Preview, rules::ruff::rules::NonExhaustiveMatch),
+        (Ruff, "072") => (RuleGroup::Preview, rules::ruff::rules::IncompleteContextManagerProtocol),
+        (Ruff, "073") => (RuleGroup::Preview, rules::ruff::rules::ComparisonWithRange),
+        (Ruff, "074") => (RuleGroup::Preview, rules::ruff::rules::IgnoredProcessReturnCode),
         (Ruff, "100") => (RuleGroup::Stable, rules::ruff::rules::UnusedNOQA),
         (Ruff, "101") => (RuleGroup::Preview, rules::ruff::rules::RedirectedNOQA),
         (Ruff, "200") => (RuleGroup::Stable, rules::ruff::rules::InvalidPyprojectToml),
@@ -1030,6 +1081,9 @@ pub fn code_to_rule(linter: Linter, code: &str) -> Option<(RuleGroup, Rule)> {
         // perflint
         (Perflint, "101") => (RuleGroup::Stable, rules::perflint::rules::UnnecessaryListCast),
         (Perflint, "102") => (RuleGroup::Stable, rules::perflint::rules::IncorrectDictIterator),
+        (Perflint, "103") => (RuleGroup::Preview, rules::perflint::rules::SortedConstant),
+        (Perflint, "104") => (RuleGroup::Preview, rules::perflint::rules::IterateOverRangeLen),
+        (Perflint, "105") => (RuleGroup::Preview, rules::perflint::rules::UnnecessaryCopy),
         (Perflint, "203") => (RuleGroup::Stable, rules::perflint::rules::TryExceptInLoop),
         (Perflint, "401") => (RuleGroup::Stable, rules::perflint::rules::ManualListComprehension),
         (Perflint, "402") => (RuleGroup::Stable, rules::perflint::rules::ManualListCopy),
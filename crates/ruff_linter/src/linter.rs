@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::ops::Deref;
 use std::path::Path;
 
@@ -7,8 +8,9 @@ use colored::Colorize;
 use itertools::Itertools;
 use log::error;
 use rustc_hash::FxHashMap;
+use serde::Serialize;
 
-use ruff_diagnostics::Diagnostic;
+use ruff_diagnostics::{Diagnostic, FixAvailability};
 use ruff_notebook::Notebook;
 use ruff_python_ast::{PySourceType, Suite};
 use ruff_python_codegen::Stylist;
@@ -59,6 +61,38 @@ impl<T> LinterResult<T> {
 
 pub type FixTable = FxHashMap<Rule, usize>;
 
+/// Per-rule summary of a set of diagnostics, suitable for machine-readable
+/// output.
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+pub struct DiagnosticStatistics {
+    /// The number of diagnostics reported for the rule.
+    pub count: usize,
+    /// The number of diagnostics carrying a fix.
+    pub fixable: usize,
+    /// The number of diagnostics without a fix, for a rule that advertises
+    /// one (e.g., a fix that is only available under preview).
+    pub fixable_with_preview: usize,
+}
+
+/// Summarize the given diagnostics per rule, keyed by the rule's `noqa` code.
+///
+/// Each [`Diagnostic`] is counted exactly once, regardless of how many edits
+/// its fix carries (e.g., a notebook fix that spans multiple cells).
+pub fn diagnostic_statistics(diagnostics: &[Diagnostic]) -> BTreeMap<String, DiagnosticStatistics> {
+    let mut statistics: BTreeMap<String, DiagnosticStatistics> = BTreeMap::new();
+    for diagnostic in diagnostics {
+        let rule = diagnostic.kind.rule();
+        let entry = statistics.entry(rule.noqa_code().to_string()).or_default();
+        entry.count += 1;
+        if diagnostic.fix.is_some() {
+            entry.fixable += 1;
+        } else if !matches!(rule.fixable(), FixAvailability::None) {
+            entry.fixable_with_preview += 1;
+        }
+    }
+    statistics
+}
+
 pub struct FixerResult<'a> {
     /// The result returned by the linter, after applying any fixes.
     pub result: LinterResult<Vec<Message>>,
@@ -465,6 +499,36 @@ pub fn lint_only(
     result.map(|diagnostics| diagnostics_to_messages(diagnostics, path, &locator, &directives))
 }
 
+/// Lint the given source code string, returning the generated diagnostics as
+/// messages.
+///
+/// This is a convenience wrapper around [`lint_only`] for embedding the
+/// linter in other tools: it builds the [`SourceKind`] internally and lints
+/// with `noqa` directives respected, so behavior matches the CLI. Notebooks
+/// cannot be linted as a plain string; construct a
+/// [`SourceKind::IpyNotebook`] and call [`lint_only`] directly instead.
+pub fn lint_source(
+    code: &str,
+    source_type: PySourceType,
+    settings: &LinterSettings,
+) -> Result<LinterResult<Vec<Message>>> {
+    if source_type.is_ipynb() {
+        return Err(anyhow!(
+            "Notebooks cannot be linted from a plain string; use `lint_only` with a `SourceKind::IpyNotebook`"
+        ));
+    }
+    let source_kind = SourceKind::Python(code.to_string());
+    Ok(lint_only(
+        Path::new("<string>"),
+        None,
+        settings,
+        flags::Noqa::Enabled,
+        &source_kind,
+        source_type,
+        ParseSource::None,
+    ))
+}
+
 /// Convert from diagnostics to messages.
 fn diagnostics_to_messages(
     diagnostics: Vec<Diagnostic>,
@@ -780,11 +844,54 @@ mod tests {
 
     use ruff_notebook::{Notebook, NotebookError};
 
-    use crate::registry::Rule;
+    use ruff_diagnostics::{Diagnostic, Edit, Fix};
+    use ruff_text_size::TextRange;
+
+    use crate::linter::diagnostic_statistics;
+    use crate::registry::{AsRule, Rule};
+    use crate::rules::ruff::rules::AssertDictSetEquality;
     use crate::source_kind::SourceKind;
     use crate::test::{assert_notebook_path, test_contents, TestedNotebook};
     use crate::{assert_messages, settings};
 
+    #[test]
+    fn lint_source_from_string() {
+        let result = super::lint_source(
+            "import os\n",
+            ruff_python_ast::PySourceType::Python,
+            &settings::LinterSettings::for_rule(Rule::UnusedImport),
+        )
+        .unwrap();
+        assert_eq!(result.data.len(), 1);
+        assert_eq!(result.data[0].kind.rule(), Rule::UnusedImport);
+
+        // Raw notebook JSON cannot be linted as plain source.
+        assert!(super::lint_source(
+            "{}",
+            ruff_python_ast::PySourceType::Ipynb,
+            &settings::LinterSettings::for_rule(Rule::UnusedImport),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn diagnostic_statistics_counts_each_diagnostic_once() {
+        let mut fixable = Diagnostic::new(AssertDictSetEquality, TextRange::default());
+        // A fix with multiple edits (e.g., spanning notebook cells) still
+        // counts as a single fixable diagnostic.
+        fixable.set_fix(Fix::safe_edits(
+            Edit::insertion("a".to_string(), 0.into()),
+            [Edit::insertion("b".to_string(), 2.into())],
+        ));
+        let unfixable = Diagnostic::new(AssertDictSetEquality, TextRange::default());
+
+        let statistics = diagnostic_statistics(&[fixable, unfixable]);
+        let entry = &statistics[&Rule::AssertDictSetEquality.noqa_code().to_string()];
+        assert_eq!(entry.count, 2);
+        assert_eq!(entry.fixable, 1);
+        assert_eq!(entry.fixable_with_preview, 0);
+    }
+
     /// Construct a path to a Jupyter notebook in the `resources/test/fixtures/jupyter` directory.
     fn notebook_path(path: impl AsRef<Path>) -> std::path::PathBuf {
         Path::new("../ruff_notebook/resources/test/fixtures/jupyter").join(path)
@@ -7,7 +7,7 @@ use strum_macros::EnumIter;
 
 use crate::codes::RuleCodePrefix;
 use crate::codes::RuleIter;
-use crate::registry::{Linter, Rule, RuleNamespace};
+use crate::registry::{Linter, Rule, RuleNamespace, RuleSet};
 use crate::rule_redirects::get_redirect;
 use crate::settings::types::PreviewMode;
 
@@ -236,6 +236,22 @@ impl RuleSelector {
     pub fn is_exact(&self) -> bool {
         matches!(self, Self::Rule { .. })
     }
+
+    /// Expand the selector into the concrete [`RuleSet`] it would enable under
+    /// the given [`PreviewMode`].
+    ///
+    /// This mirrors the filtering applied during rule resolution: preview and
+    /// deprecated rules are included or excluded based on `preview`, while
+    /// removed rules are never included (selecting one by exact code is an
+    /// error during resolution, rather than enabling the rule).
+    pub fn expand(&self, preview: PreviewMode) -> RuleSet {
+        self.rules(&PreviewOptions {
+            mode: preview,
+            require_explicit: false,
+        })
+        .filter(|rule| !rule.is_removed())
+        .collect()
+    }
 }
 
 pub enum RuleSelectorIter {
@@ -269,9 +285,9 @@ pub struct PreviewOptions {
 #[cfg(feature = "schemars")]
 mod schema {
     use itertools::Itertools;
-    use schemars::JsonSchema;
     use schemars::_serde_json::Value;
     use schemars::schema::{InstanceType, Schema, SchemaObject};
+    use schemars::JsonSchema;
     use strum::IntoEnumIterator;
 
     use crate::registry::RuleNamespace;
@@ -508,3 +524,36 @@ pub mod clap_completion {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::registry::Rule;
+    use crate::settings::types::PreviewMode;
+
+    use super::RuleSelector;
+
+    #[test]
+    fn expand_filters_rule_groups() {
+        // A prefix selector expands to its stable rules when preview is
+        // disabled...
+        let selector = RuleSelector::from_str("PTH").unwrap();
+        let stable = selector.expand(PreviewMode::Disabled);
+        assert!(stable.contains(Rule::OsPathJoin));
+        assert!(!stable.contains(Rule::OsPathRelpath));
+
+        // ...and includes preview rules when preview is enabled.
+        let preview = selector.expand(PreviewMode::Enabled);
+        assert!(preview.contains(Rule::OsPathRelpath));
+
+        // `ALL` never includes nursery or removed rules.
+        let all = RuleSelector::All.expand(PreviewMode::Disabled);
+        #[allow(deprecated)]
+        {
+            assert!(all
+                .iter()
+                .all(|rule| !rule.is_nursery() && !rule.is_removed()));
+        }
+    }
+}
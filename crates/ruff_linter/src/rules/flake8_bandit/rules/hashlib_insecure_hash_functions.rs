@@ -22,6 +22,12 @@ use super::super::helpers::string_literal;
 /// Avoid using weak or broken cryptographic hash functions in security
 /// contexts. Instead, use a known secure hash function such as SHA256.
 ///
+/// On Python 3.9 and later, `hashlib` constructors accept a
+/// `usedforsecurity` keyword argument. Passing `usedforsecurity=False` marks
+/// the call as legitimate for non-security uses (e.g., computing cache
+/// keys), and suppresses this rule. The exemption applies only to `hashlib`
+/// calls: `crypt` has no equivalent parameter.
+///
 /// ## Example
 /// ```python
 /// import hashlib
@@ -114,11 +120,11 @@ fn detect_insecure_hashlib_calls(
                 return;
             };
 
-            // `hashlib.new` accepts both lowercase and uppercase names for hash
-            // functions.
+            // `hashlib.new` accepts hash names in any case (e.g., both `md5`
+            // and `MD5`).
             if matches!(
-                hash_func_name,
-                "md4" | "md5" | "sha" | "sha1" | "MD4" | "MD5" | "SHA" | "SHA1"
+                hash_func_name.to_lowercase().as_str(),
+                "md2" | "md4" | "md5" | "sha" | "sha1"
             ) {
                 checker.diagnostics.push(Diagnostic::new(
                     HashlibInsecureHashFunction {
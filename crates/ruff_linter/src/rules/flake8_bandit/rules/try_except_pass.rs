@@ -1,4 +1,4 @@
-use ruff_python_ast::{ExceptHandler, Expr, Stmt};
+use ruff_python_ast::{self as ast, ExceptHandler, Expr, Stmt};
 
 use ruff_diagnostics::{Diagnostic, Violation};
 use ruff_macros::{derive_message_formats, violation};
@@ -8,7 +8,8 @@ use crate::checkers::ast::Checker;
 use crate::rules::flake8_bandit::helpers::is_untyped_exception;
 
 /// ## What it does
-/// Checks for uses of the `try`-`except`-`pass` pattern.
+/// Checks for uses of the `try`-`except`-`pass` pattern, including handlers
+/// whose body is a lone `...`.
 ///
 /// ## Why is this bad?
 /// The `try`-`except`-`pass` pattern suppresses all exceptions. Suppressing
@@ -55,7 +56,9 @@ pub(crate) fn try_except_pass(
     body: &[Stmt],
     check_typed_exception: bool,
 ) {
-    if matches!(body, [Stmt::Pass(_)]) {
+    if matches!(body, [Stmt::Pass(_)])
+        || matches!(body, [Stmt::Expr(ast::StmtExpr { value, range: _ })] if value.is_ellipsis_literal_expr())
+    {
         if check_typed_exception || is_untyped_exception(type_, checker.semantic()) {
             checker
                 .diagnostics
@@ -1,4 +1,4 @@
-use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
 use ruff_macros::{derive_message_formats, violation};
 use ruff_python_ast::helpers::is_const_true;
 use ruff_python_ast::statement_visitor::{walk_stmt, StatementVisitor};
@@ -55,6 +55,11 @@ use crate::checkers::ast::Checker;
 ///     logging.exception("Something went wrong")
 /// ```
 ///
+/// ## Fix safety
+/// For `except BaseException`, a fix is offered to catch `Exception` instead,
+/// which excludes `KeyboardInterrupt` and `SystemExit`. The fix is marked as
+/// unsafe, as it changes which exceptions the handler catches.
+///
 /// ## References
 /// - [Python documentation: The `try` statement](https://docs.python.org/3/reference/compound_stmts.html#the-try-statement)
 /// - [Python documentation: Exception hierarchy](https://docs.python.org/3/library/exceptions.html#exception-hierarchy)
@@ -64,11 +69,21 @@ pub struct BlindExcept {
 }
 
 impl Violation for BlindExcept {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
     #[derive_message_formats]
     fn message(&self) -> String {
         let BlindExcept { name } = self;
         format!("Do not catch blind exception: `{name}`")
     }
+
+    fn fix_title(&self) -> Option<String> {
+        let BlindExcept { name } = self;
+        match name.as_str() {
+            "BaseException" => Some("Catch `Exception` instead".to_string()),
+            _ => None,
+        }
+    }
 }
 
 /// BLE001
@@ -104,12 +119,23 @@ pub(crate) fn blind_except(
         return;
     }
 
-    checker.diagnostics.push(Diagnostic::new(
+    let mut diagnostic = Diagnostic::new(
         BlindExcept {
             name: builtin_exception_type.to_string(),
         },
         type_.range(),
-    ));
+    );
+
+    // `except BaseException` has a narrower drop-in replacement; `except
+    // Exception` is already as narrow as a blanket handler gets.
+    if builtin_exception_type == "BaseException" && semantic.has_builtin_binding("Exception") {
+        diagnostic.set_fix(Fix::unsafe_edit(Edit::range_replacement(
+            "Exception".to_string(),
+            type_.range(),
+        )));
+    }
+
+    checker.diagnostics.push(diagnostic);
 }
 
 /// A visitor to detect whether the exception with the given name was re-raised.
@@ -1,9 +1,10 @@
-use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_diagnostics::{AlwaysFixableViolation, Applicability, Diagnostic, Fix};
 use ruff_macros::{derive_message_formats, violation};
 use ruff_python_ast::{self as ast};
 use ruff_text_size::Ranged;
 
 use crate::checkers::ast::Checker;
+use crate::fix::edits::add_argument;
 
 /// ## What it does
 /// Checks for `warnings.warn` calls without an explicit `stacklevel` keyword
@@ -26,14 +27,23 @@ use crate::checkers::ast::Checker;
 /// ```python
 /// warnings.warn("This is a warning", stacklevel=2)
 /// ```
+///
+/// ## Fix safety
+/// This rule's fix is marked as unsafe for `warnings.warn` calls that contain
+/// `**kwargs`, as adding a `stacklevel` keyword argument to such a call may
+/// lead to a duplicate keyword argument error.
 #[violation]
 pub struct NoExplicitStacklevel;
 
-impl Violation for NoExplicitStacklevel {
+impl AlwaysFixableViolation for NoExplicitStacklevel {
     #[derive_message_formats]
     fn message(&self) -> String {
         format!("No explicit `stacklevel` keyword argument found")
     }
+
+    fn fix_title(&self) -> String {
+        "Add explicit `stacklevel=2`".to_string()
+    }
 }
 
 /// B028
@@ -46,11 +56,30 @@ pub(crate) fn no_explicit_stacklevel(checker: &mut Checker, call: &ast::ExprCall
         return;
     }
 
-    if call.arguments.find_keyword("stacklevel").is_some() {
+    // `stacklevel` may also be passed positionally, as the third argument.
+    if call.arguments.find_keyword("stacklevel").is_some() || call.arguments.args.len() >= 3 {
         return;
     }
 
-    checker
-        .diagnostics
-        .push(Diagnostic::new(NoExplicitStacklevel, call.func.range()));
+    checker.diagnostics.push(
+        Diagnostic::new(NoExplicitStacklevel, call.func.range()).with_fix(Fix::applicable_edit(
+            add_argument(
+                "stacklevel=2",
+                &call.arguments,
+                checker.indexer().comment_ranges(),
+                checker.locator().contents(),
+            ),
+            // If the function call contains `**kwargs`, mark the fix as unsafe.
+            if call
+                .arguments
+                .keywords
+                .iter()
+                .any(|keyword| keyword.arg.is_none())
+            {
+                Applicability::Unsafe
+            } else {
+                Applicability::Safe
+            },
+        )),
+    );
 }
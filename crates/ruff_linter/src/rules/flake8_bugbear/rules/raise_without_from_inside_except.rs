@@ -1,10 +1,11 @@
 use ruff_python_ast as ast;
 use ruff_python_ast::Stmt;
 
-use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
 use ruff_macros::{derive_message_formats, violation};
 use ruff_python_ast::helpers::RaiseStatementVisitor;
 use ruff_python_ast::statement_visitor::StatementVisitor;
+use ruff_text_size::Ranged;
 
 use crate::checkers::ast::Checker;
 
@@ -44,12 +45,20 @@ use crate::checkers::ast::Checker;
 ///         raise UserWarning("...") from exc
 /// ```
 ///
+/// ## Fix safety
+/// When the exception handler binds the caught exception to a name, a fix is
+/// offered that appends `from <name>` to the `raise`. The fix is marked as
+/// unsafe, as it changes the new exception's `__cause__`, which a caller may
+/// be inspecting.
+///
 /// ## References
 /// - [Python documentation: `raise` statement](https://docs.python.org/3/reference/simple_stmts.html#the-raise-statement)
 #[violation]
 pub struct RaiseWithoutFromInsideExcept;
 
 impl Violation for RaiseWithoutFromInsideExcept {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
     #[derive_message_formats]
     fn message(&self) -> String {
         format!(
@@ -57,6 +66,10 @@ impl Violation for RaiseWithoutFromInsideExcept {
              from None` to distinguish them from errors in exception handling"
         )
     }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Add exception cause".to_string())
+    }
 }
 
 /// B904
@@ -93,9 +106,14 @@ pub(crate) fn raise_without_from_inside_except(
                     }
                 }
 
-                checker
-                    .diagnostics
-                    .push(Diagnostic::new(RaiseWithoutFromInsideExcept, range));
+                let mut diagnostic = Diagnostic::new(RaiseWithoutFromInsideExcept, range);
+                if let Some(name) = name {
+                    diagnostic.set_fix(Fix::unsafe_edit(Edit::insertion(
+                        format!(" from {name}"),
+                        exc.end(),
+                    )));
+                }
+                checker.diagnostics.push(diagnostic);
             }
         }
     }
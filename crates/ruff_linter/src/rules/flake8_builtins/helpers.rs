@@ -1,11 +1,16 @@
 use ruff_python_ast::PySourceType;
 use ruff_python_stdlib::builtins::{is_ipython_builtin, is_python_builtin};
+use rustc_hash::FxHashSet;
 
 pub(super) fn shadows_builtin(
     name: &str,
     ignorelist: &[String],
+    extra_builtins: &FxHashSet<String>,
     source_type: PySourceType,
 ) -> bool {
+    if extra_builtins.contains(name) {
+        return true;
+    }
     if is_python_builtin(name) || source_type.is_ipynb() && is_ipython_builtin(name) {
         ignorelist.iter().all(|ignore| ignore != name)
     } else {
@@ -43,6 +43,34 @@ mod tests {
             &LinterSettings {
                 flake8_builtins: super::settings::Settings {
                     builtins_ignorelist: vec!["id".to_string(), "dir".to_string()],
+                    ..Default::default()
+                },
+                ..LinterSettings::for_rules(vec![rule_code])
+            },
+        )?;
+
+        assert_messages!(snapshot, diagnostics);
+        Ok(())
+    }
+
+    #[test_case(Rule::BuiltinVariableShadowing, Path::new("A001_extra_builtins.py"))]
+    #[test_case(Rule::BuiltinArgumentShadowing, Path::new("A002_extra_builtins.py"))]
+    fn extra_builtins(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!(
+            "{}_{}_extra_builtins",
+            rule_code.noqa_code(),
+            path.to_string_lossy()
+        );
+
+        let diagnostics = test_path(
+            Path::new("flake8_builtins").join(path).as_path(),
+            &LinterSettings {
+                flake8_builtins: super::settings::Settings {
+                    extra_builtins: ["List", "Dict", "Optional"]
+                        .map(String::from)
+                        .into_iter()
+                        .collect(),
+                    ..Default::default()
                 },
                 ..LinterSettings::for_rules(vec![rule_code])
             },
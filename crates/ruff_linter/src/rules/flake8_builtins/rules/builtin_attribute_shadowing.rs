@@ -99,6 +99,7 @@ pub(crate) fn builtin_attribute_shadowing(
         if shadows_builtin(
             name,
             &checker.settings.flake8_builtins.builtins_ignorelist,
+            &checker.settings.flake8_builtins.extra_builtins,
             checker.source_type,
         ) {
             // Ignore explicit overrides.
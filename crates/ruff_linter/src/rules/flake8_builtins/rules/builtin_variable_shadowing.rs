@@ -63,6 +63,7 @@ pub(crate) fn builtin_variable_shadowing(checker: &mut Checker, name: &str, rang
     if shadows_builtin(
         name,
         &checker.settings.flake8_builtins.builtins_ignorelist,
+        &checker.settings.flake8_builtins.extra_builtins,
         checker.source_type,
     ) {
         checker.diagnostics.push(Diagnostic::new(
@@ -2,11 +2,13 @@
 
 use crate::display_settings;
 use ruff_macros::CacheKey;
+use rustc_hash::FxHashSet;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Clone, Default, CacheKey)]
 pub struct Settings {
     pub builtins_ignorelist: Vec<String>,
+    pub extra_builtins: FxHashSet<String>,
 }
 
 impl Display for Settings {
@@ -15,7 +17,8 @@ impl Display for Settings {
             formatter = f,
             namespace = "linter.flake8_builtins",
             fields = [
-                self.builtins_ignorelist | array
+                self.builtins_ignorelist | array,
+                self.extra_builtins | array
             ]
         }
         Ok(())
@@ -1,11 +1,12 @@
 use ruff_python_ast::{Expr, Stmt};
 
-use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_diagnostics::{Diagnostic, Fix, FixAvailability, Violation};
 use ruff_macros::{derive_message_formats, violation};
 use ruff_python_ast::name::QualifiedName;
 use ruff_text_size::Ranged;
 
 use crate::checkers::ast::Checker;
+use crate::fix::edits::delete_stmt;
 use crate::rules::flake8_debugger::types::DebuggerUsingType;
 
 /// ## What it does
@@ -27,6 +28,11 @@ use crate::rules::flake8_debugger::types::DebuggerUsingType;
 ///     breakpoint()
 /// ```
 ///
+/// ## Fix safety
+/// A fix is only offered for a standalone `breakpoint()` statement, which is
+/// a no-op outside a debugging session and can be removed safely. Other
+/// debugger calls and imports are left for manual review.
+///
 /// ## References
 /// - [Python documentation: `pdb` — The Python Debugger](https://docs.python.org/3/library/pdb.html)
 /// - [Python documentation: `logging` — Logging facility for Python](https://docs.python.org/3/library/logging.html)
@@ -36,6 +42,8 @@ pub struct Debugger {
 }
 
 impl Violation for Debugger {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
     #[derive_message_formats]
     fn message(&self) -> String {
         let Debugger { using_type } = self;
@@ -44,6 +52,10 @@ impl Violation for Debugger {
             DebuggerUsingType::Import(name) => format!("Import for `{name}` found"),
         }
     }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Remove `breakpoint()`".to_string())
+    }
 }
 
 /// Checks for the presence of a debugger call.
@@ -60,9 +72,21 @@ pub(crate) fn debugger_call(checker: &mut Checker, expr: &Expr, func: &Expr) {
                 }
             })
     {
-        checker
-            .diagnostics
-            .push(Diagnostic::new(Debugger { using_type }, expr.range()));
+        let is_breakpoint = matches!(&using_type, DebuggerUsingType::Call(name) if matches!(name.as_str(), "breakpoint" | "builtins.breakpoint"));
+        let mut diagnostic = Diagnostic::new(Debugger { using_type }, expr.range());
+        // Remove a standalone `breakpoint()` statement.
+        if is_breakpoint
+            && checker.semantic().current_expression_parent().is_none()
+            && checker.semantic().current_statement().is_expr_stmt()
+        {
+            let statement = checker.semantic().current_statement();
+            let parent = checker.semantic().current_statement_parent();
+            let edit = delete_stmt(statement, parent, checker.locator(), checker.indexer());
+            diagnostic.set_fix(Fix::safe_edit(edit).isolate(Checker::isolation(
+                checker.semantic().current_statement_parent_id(),
+            )));
+        }
+        checker.diagnostics.push(diagnostic);
     }
 }
 
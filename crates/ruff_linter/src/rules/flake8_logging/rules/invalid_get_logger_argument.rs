@@ -24,6 +24,11 @@ use crate::checkers::ast::Checker;
 ///
 /// * `__cached__` - the pathname of the module's compiled version, such as `foo/__pycache__/bar.cpython-311.pyc`.
 /// * `__file__` - the pathname of the module, such as `foo/bar.py`.
+/// * `__spec__.name` - the fully qualified module name, which matches `__name__`
+///   except when the module is run directly; prefer the conventional spelling.
+///
+/// It also detects string literals that duplicate the module's own dotted
+/// name, which fall out of sync when the module is moved or renamed.
 ///
 /// ## Example
 /// ```python
@@ -62,16 +67,33 @@ pub(crate) fn invalid_get_logger_argument(checker: &mut Checker, call: &ast::Exp
         return;
     }
 
-    let Some(Expr::Name(expr @ ast::ExprName { id, .. })) = call.arguments.find_argument("name", 0)
-    else {
+    let Some(argument) = call.arguments.find_argument("name", 0) else {
         return;
     };
 
-    if !matches!(id.as_ref(), "__file__" | "__cached__") {
-        return;
-    }
+    let is_invalid = match argument {
+        Expr::Name(ast::ExprName { id, .. }) => {
+            matches!(id.as_ref(), "__file__" | "__cached__")
+                && checker.semantic().has_builtin_binding(id)
+        }
+        Expr::Attribute(ast::ExprAttribute { value, attr, .. }) => {
+            attr.as_str() == "name"
+                && value.as_name_expr().is_some_and(|value| {
+                    value.id == "__spec__" && checker.semantic().has_builtin_binding(&value.id)
+                })
+        }
+        Expr::StringLiteral(ast::ExprStringLiteral { value, .. }) => {
+            checker.module().qualified_name().is_some_and(|path| {
+                value
+                    .to_str()
+                    .split('.')
+                    .eq(path.iter().map(String::as_str))
+            })
+        }
+        _ => return,
+    };
 
-    if !checker.semantic().has_builtin_binding(id) {
+    if !is_invalid {
         return;
     }
 
@@ -83,11 +105,11 @@ pub(crate) fn invalid_get_logger_argument(checker: &mut Checker, call: &ast::Exp
         return;
     }
 
-    let mut diagnostic = Diagnostic::new(InvalidGetLoggerArgument, expr.range());
+    let mut diagnostic = Diagnostic::new(InvalidGetLoggerArgument, argument.range());
     if checker.semantic().has_builtin_binding("__name__") {
         diagnostic.set_fix(Fix::unsafe_edit(Edit::range_replacement(
             "__name__".to_string(),
-            expr.range(),
+            argument.range(),
         )));
     }
     checker.diagnostics.push(diagnostic);
@@ -2,6 +2,7 @@ pub(crate) use duplicate_class_field_definition::*;
 pub(crate) use multiple_starts_ends_with::*;
 pub(crate) use non_unique_enums::*;
 pub(crate) use reimplemented_container_builtin::*;
+pub(crate) use unnecessary_dict_comprehension_wrapper::*;
 pub(crate) use unnecessary_dict_kwargs::*;
 pub(crate) use unnecessary_placeholder::*;
 pub(crate) use unnecessary_range_start::*;
@@ -11,6 +12,7 @@ mod duplicate_class_field_definition;
 mod multiple_starts_ends_with;
 mod non_unique_enums;
 mod reimplemented_container_builtin;
+mod unnecessary_dict_comprehension_wrapper;
 mod unnecessary_dict_kwargs;
 mod unnecessary_placeholder;
 mod unnecessary_range_start;
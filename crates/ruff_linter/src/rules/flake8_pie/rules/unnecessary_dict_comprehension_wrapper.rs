@@ -0,0 +1,63 @@
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Expr};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `dict()` calls that wrap a dict comprehension.
+///
+/// ## Why is this bad?
+/// A dict comprehension already produces a dictionary, so wrapping it in a
+/// `dict()` call copies the dictionary without changing its contents. The
+/// wrapper can be removed, making the code more readable.
+///
+/// ## Example
+/// ```python
+/// foo = dict({key: value for key, value in pairs})
+/// ```
+///
+/// Use instead:
+/// ```python
+/// foo = {key: value for key, value in pairs}
+/// ```
+///
+/// ## References
+/// - [Python documentation: Dictionary displays](https://docs.python.org/3/reference/expressions.html#dictionary-displays)
+#[violation]
+pub struct UnnecessaryDictComprehensionWrapper;
+
+impl AlwaysFixableViolation for UnnecessaryDictComprehensionWrapper {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("Unnecessary `dict()` call around a dict comprehension")
+    }
+
+    fn fix_title(&self) -> String {
+        "Remove unnecessary `dict()` call".to_string()
+    }
+}
+
+/// PIE811
+pub(crate) fn unnecessary_dict_comprehension_wrapper(checker: &mut Checker, call: &ast::ExprCall) {
+    // Extra keywords (e.g., `dict(comprehension, extra=1)`) change the result.
+    if !call.arguments.keywords.is_empty() {
+        return;
+    }
+
+    let [Expr::DictComp(comprehension)] = &*call.arguments.args else {
+        return;
+    };
+
+    if !checker.semantic().match_builtin_expr(&call.func, "dict") {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(UnnecessaryDictComprehensionWrapper, call.range());
+    diagnostic.set_fix(Fix::safe_edit(Edit::range_replacement(
+        checker.locator().slice(comprehension.range()).to_string(),
+        call.range(),
+    )));
+    checker.diagnostics.push(diagnostic);
+}
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use ruff_diagnostics::{AlwaysFixableViolation, Violation};
+use ruff_diagnostics::{AlwaysFixableViolation, FixAvailability, Violation};
 use ruff_diagnostics::{Diagnostic, Edit, Fix};
 use ruff_macros::{derive_message_formats, violation};
 use ruff_python_ast::identifier::Identifier;
@@ -116,11 +116,17 @@ pub struct PytestFixturePositionalArgs {
 }
 
 impl Violation for PytestFixturePositionalArgs {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
     #[derive_message_formats]
     fn message(&self) -> String {
         let PytestFixturePositionalArgs { function } = self;
         format!("Configuration for fixture `{function}` specified via positional args, use kwargs")
     }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Use a `scope` keyword argument".to_string())
+    }
 }
 
 /// ## What it does
@@ -708,12 +714,23 @@ fn check_fixture_decorator(checker: &mut Checker, func_name: &str, decorator: &D
 
             if checker.enabled(Rule::PytestFixturePositionalArgs) {
                 if !arguments.args.is_empty() {
-                    checker.diagnostics.push(Diagnostic::new(
+                    let mut diagnostic = Diagnostic::new(
                         PytestFixturePositionalArgs {
                             function: func_name.to_string(),
                         },
                         decorator.range(),
-                    ));
+                    );
+                    // The first positional parameter to `pytest.fixture` is `scope`, so a
+                    // lone positional argument maps directly onto the `scope` keyword.
+                    if let [arg] = &*arguments.args {
+                        if !arg.is_starred_expr() && arguments.find_keyword("scope").is_none() {
+                            diagnostic.set_fix(Fix::safe_edit(Edit::insertion(
+                                "scope=".to_string(),
+                                arg.start(),
+                            )));
+                        }
+                    }
+                    checker.diagnostics.push(diagnostic);
                 }
             }
 
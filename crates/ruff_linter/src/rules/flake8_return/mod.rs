@@ -2,6 +2,7 @@
 mod branch;
 mod helpers;
 pub(crate) mod rules;
+pub mod settings;
 mod visitor;
 
 #[cfg(test)]
@@ -35,6 +36,21 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn flag_annotated_assigns() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("flake8_return/RET504_annotated.py"),
+            &settings::LinterSettings {
+                flake8_return: super::settings::Settings {
+                    flag_annotated_assigns: true,
+                },
+                ..settings::LinterSettings::for_rule(Rule::UnnecessaryAssign)
+            },
+        )?;
+        assert_messages!(diagnostics);
+        Ok(())
+    }
+
     #[test_case(Rule::SuperfluousElseReturn, Path::new("RET505.py"))]
     #[test_case(Rule::SuperfluousElseRaise, Path::new("RET506.py"))]
     #[test_case(Rule::SuperfluousElseContinue, Path::new("RET507.py"))]
@@ -166,6 +166,9 @@ impl AlwaysFixableViolation for ImplicitReturn {
 /// def foo():
 ///     return 1
 /// ```
+///
+/// ## Options
+/// - `lint.flake8-return.flag-annotated-assigns`
 #[violation]
 pub struct UnnecessaryAssign {
     name: String,
@@ -535,18 +538,6 @@ fn implicit_return(checker: &mut Checker, stmt: &Stmt) {
 /// RET504
 fn unnecessary_assign(checker: &mut Checker, stack: &Stack) {
     for (assign, return_, stmt) in &stack.assignment_return {
-        // Identify, e.g., `return x`.
-        let Some(value) = return_.value.as_ref() else {
-            continue;
-        };
-
-        let Expr::Name(ast::ExprName {
-            id: returned_id, ..
-        }) = value.as_ref()
-        else {
-            continue;
-        };
-
         // Identify, e.g., `x = 1`.
         if assign.targets.len() > 1 {
             continue;
@@ -556,74 +547,127 @@ fn unnecessary_assign(checker: &mut Checker, stack: &Stack) {
             continue;
         };
 
-        let Expr::Name(ast::ExprName {
-            id: assigned_id, ..
-        }) = target
-        else {
-            continue;
-        };
+        check_unnecessary_assign(
+            checker,
+            target,
+            assign.range(),
+            assign.start(),
+            return_,
+            stmt,
+            stack,
+        );
+    }
 
-        if returned_id != assigned_id {
-            continue;
-        }
+    if checker.settings.flake8_return.flag_annotated_assigns {
+        for (assign, return_, stmt) in &stack.annotated_assignment_return {
+            // Identify, e.g., `x: int = 1` (a bare `x: int` binds nothing).
+            if assign.value.is_none() {
+                continue;
+            }
 
-        // Ignore variables that have an annotation defined elsewhere.
-        if stack.annotations.contains(assigned_id.as_str()) {
-            continue;
+            check_unnecessary_assign(
+                checker,
+                &assign.target,
+                assign.range(),
+                // The annotation may itself contain an `=` (e.g., in a
+                // subscript); search for the assignment's `=` after it.
+                assign.annotation.end(),
+                return_,
+                stmt,
+                stack,
+            );
         }
+    }
+}
 
-        // Ignore `nonlocal` and `global` variables.
-        if stack.non_locals.contains(assigned_id.as_str()) {
-            continue;
-        }
+/// Flag an assignment to `target` that immediately precedes a `return` of the
+/// same name.
+fn check_unnecessary_assign(
+    checker: &mut Checker,
+    target: &Expr,
+    assign_range: TextRange,
+    search_from: TextSize,
+    return_: &ast::StmtReturn,
+    stmt: &Stmt,
+    stack: &Stack,
+) {
+    // Identify, e.g., `return x`.
+    let Some(value) = return_.value.as_ref() else {
+        return;
+    };
+
+    let Expr::Name(ast::ExprName {
+        id: returned_id, ..
+    }) = value.as_ref()
+    else {
+        return;
+    };
 
-        let mut diagnostic = Diagnostic::new(
-            UnnecessaryAssign {
-                name: assigned_id.to_string(),
+    let Expr::Name(ast::ExprName {
+        id: assigned_id, ..
+    }) = target
+    else {
+        return;
+    };
+
+    if returned_id != assigned_id {
+        return;
+    }
+
+    // Ignore variables that have an annotation defined elsewhere.
+    if stack.annotations.contains(assigned_id.as_str()) {
+        return;
+    }
+
+    // Ignore `nonlocal` and `global` variables.
+    if stack.non_locals.contains(assigned_id.as_str()) {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(
+        UnnecessaryAssign {
+            name: assigned_id.to_string(),
+        },
+        value.range(),
+    );
+    diagnostic.try_set_fix(|| {
+        // Delete the `return` statement. There's no need to treat this as an isolated
+        // edit, since we're editing the preceding statement, so no conflicting edit would
+        // be allowed to remove that preceding statement.
+        let delete_return = edits::delete_stmt(stmt, None, checker.locator(), checker.indexer());
+
+        // Replace the `x = 1` statement with `return 1`.
+        let content = checker.locator().slice(assign_range);
+        let search_offset = usize::from(search_from - assign_range.start());
+        let equals_index = content[search_offset..]
+            .find('=')
+            .map(|index| index + search_offset)
+            .ok_or(anyhow::anyhow!("expected '=' in assignment statement"))?;
+        let after_equals = equals_index + 1;
+
+        let replace_assign = Edit::range_replacement(
+            // If necessary, add whitespace after the `return` keyword.
+            // Ex) Convert `x=y` to `return y` (instead of `returny`).
+            if content[after_equals..]
+                .chars()
+                .next()
+                .is_some_and(is_python_whitespace)
+            {
+                "return".to_string()
+            } else {
+                "return ".to_string()
             },
-            value.range(),
+            // Replace from the start of the assignment statement to the end of the equals
+            // sign.
+            TextRange::new(
+                assign_range.start(),
+                assign_range.start().add(TextSize::try_from(after_equals)?),
+            ),
         );
-        diagnostic.try_set_fix(|| {
-            // Delete the `return` statement. There's no need to treat this as an isolated
-            // edit, since we're editing the preceding statement, so no conflicting edit would
-            // be allowed to remove that preceding statement.
-            let delete_return =
-                edits::delete_stmt(stmt, None, checker.locator(), checker.indexer());
-
-            // Replace the `x = 1` statement with `return 1`.
-            let content = checker.locator().slice(assign);
-            let equals_index = content
-                .find('=')
-                .ok_or(anyhow::anyhow!("expected '=' in assignment statement"))?;
-            let after_equals = equals_index + 1;
-
-            let replace_assign = Edit::range_replacement(
-                // If necessary, add whitespace after the `return` keyword.
-                // Ex) Convert `x=y` to `return y` (instead of `returny`).
-                if content[after_equals..]
-                    .chars()
-                    .next()
-                    .is_some_and(is_python_whitespace)
-                {
-                    "return".to_string()
-                } else {
-                    "return ".to_string()
-                },
-                // Replace from the start of the assignment statement to the end of the equals
-                // sign.
-                TextRange::new(
-                    assign.start(),
-                    assign
-                        .range()
-                        .start()
-                        .add(TextSize::try_from(after_equals)?),
-                ),
-            );
 
-            Ok(Fix::unsafe_edits(replace_assign, [delete_return]))
-        });
-        checker.diagnostics.push(diagnostic);
-    }
+        Ok(Fix::unsafe_edits(replace_assign, [delete_return]))
+    });
+    checker.diagnostics.push(diagnostic);
 }
 
 /// RET505, RET506, RET507, RET508
@@ -0,0 +1,23 @@
+//! Settings for the `flake8-return` plugin.
+
+use crate::display_settings;
+use ruff_macros::CacheKey;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Default, CacheKey)]
+pub struct Settings {
+    pub flag_annotated_assigns: bool,
+}
+
+impl Display for Settings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        display_settings! {
+            formatter = f,
+            namespace = "linter.flake8_return",
+            fields = [
+                self.flag_annotated_assigns
+            ]
+        }
+        Ok(())
+    }
+}
@@ -35,6 +35,14 @@ pub(super) struct Stack<'data> {
     /// removal for the `return` statement.
     pub(super) assignment_return:
         Vec<(&'data ast::StmtAssign, &'data ast::StmtReturn, &'data Stmt)>,
+    /// The annotated-`assignment`-to-`return` statement pairs in the current
+    /// function, as in `x: int = foo(); return x`. Only considered when
+    /// `flag-annotated-assigns` is enabled.
+    pub(super) annotated_assignment_return: Vec<(
+        &'data ast::StmtAnnAssign,
+        &'data ast::StmtReturn,
+        &'data Stmt,
+    )>,
 }
 
 pub(super) struct ReturnVisitor<'semantic, 'data> {
@@ -128,6 +136,19 @@ impl<'semantic, 'a> Visitor<'a> for ReturnVisitor<'semantic, 'a> {
                         // Example:
                         // ```python
                         // def foo():
+                        //     x: int = 1
+                        //     return x
+                        // ```
+                        Stmt::AnnAssign(stmt_assign) => {
+                            self.stack.annotated_assignment_return.push((
+                                stmt_assign,
+                                stmt_return,
+                                stmt,
+                            ));
+                        }
+                        // Example:
+                        // ```python
+                        // def foo():
                         //     with open("foo.txt", "r") as f:
                         //         x = f.read()
                         //     return x
@@ -170,12 +170,36 @@ fn key_in_dict(
     checker.diagnostics.push(diagnostic);
 }
 
+/// If the expression is an `enumerate` call over a single argument, return that
+/// argument; `for i, key in enumerate(d.keys())` is as redundant as
+/// `for key in d.keys()`.
+fn unwrap_enumerate<'a>(expr: &'a Expr, checker: &Checker) -> &'a Expr {
+    let Expr::Call(ast::ExprCall {
+        func,
+        arguments: Arguments { args, keywords, .. },
+        range: _,
+    }) = expr
+    else {
+        return expr;
+    };
+    let [argument] = &**args else {
+        return expr;
+    };
+    if !keywords.is_empty() {
+        return expr;
+    }
+    if !checker.semantic().match_builtin_expr(func, "enumerate") {
+        return expr;
+    }
+    argument
+}
+
 /// SIM118 in a `for` loop.
 pub(crate) fn key_in_dict_for(checker: &mut Checker, for_stmt: &ast::StmtFor) {
     key_in_dict(
         checker,
         &for_stmt.target,
-        &for_stmt.iter,
+        unwrap_enumerate(&for_stmt.iter, checker),
         CmpOp::In,
         for_stmt.into(),
     );
@@ -186,7 +210,7 @@ pub(crate) fn key_in_dict_comprehension(checker: &mut Checker, comprehension: &C
     key_in_dict(
         checker,
         &comprehension.target,
-        &comprehension.iter,
+        unwrap_enumerate(&comprehension.iter, checker),
         CmpOp::In,
         comprehension.into(),
     );
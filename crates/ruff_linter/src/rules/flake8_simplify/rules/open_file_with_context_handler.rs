@@ -1,9 +1,10 @@
+use ruff_python_ast::visitor::{walk_expr, Visitor};
 use ruff_python_ast::{self as ast, Expr, Stmt};
 
 use ruff_diagnostics::{Diagnostic, Violation};
 use ruff_macros::{derive_message_formats, violation};
 use ruff_python_semantic::SemanticModel;
-use ruff_text_size::Ranged;
+use ruff_text_size::{Ranged, TextSize};
 
 use crate::checkers::ast::Checker;
 
@@ -16,6 +17,9 @@ use crate::checkers::ast::Checker;
 /// the file will be closed (e.g., if an exception is raised), which can cause
 /// resource leaks.
 ///
+/// Assignments whose target is later explicitly closed within the enclosing
+/// function (e.g., in a `finally` block) are exempt.
+///
 /// ## Example
 /// ```python
 /// file = open("foo.txt")
@@ -161,6 +165,70 @@ fn is_closed(semantic: &SemanticModel) -> bool {
     attr.as_str() == "close"
 }
 
+/// Return `true` if the result of the current expression is bound to a name
+/// on which `close` is later called within the enclosing statement (e.g., at
+/// the end of the function, or in a `finally` block).
+fn is_closed_later(semantic: &SemanticModel) -> bool {
+    let target = match semantic.current_statement() {
+        Stmt::Assign(ast::StmtAssign { targets, .. }) => match targets.as_slice() {
+            [Expr::Name(name)] => name,
+            _ => return false,
+        },
+        Stmt::AnnAssign(ast::StmtAnnAssign { target, .. }) => match target.as_ref() {
+            Expr::Name(name) => name,
+            _ => return false,
+        },
+        _ => return false,
+    };
+    // At module level, the assignment has no enclosing statement to search.
+    let Some(enclosing) = semantic.current_statements().last() else {
+        return false;
+    };
+    if enclosing.range() == semantic.current_statement().range() {
+        return false;
+    }
+    let mut visitor = CloseCallVisitor {
+        name: target.id.as_str(),
+        after: target.end(),
+        found: false,
+    };
+    visitor.visit_stmt(enclosing);
+    visitor.found
+}
+
+/// Visitor searching for a `<name>.close()` call after the given offset.
+struct CloseCallVisitor<'a> {
+    name: &'a str,
+    after: TextSize,
+    found: bool,
+}
+
+impl<'a> Visitor<'a> for CloseCallVisitor<'_> {
+    fn visit_expr(&mut self, expr: &'a Expr) {
+        if self.found {
+            return;
+        }
+        if let Expr::Call(ast::ExprCall {
+            func, arguments, ..
+        }) = expr
+        {
+            if arguments.is_empty() && expr.start() >= self.after {
+                if let Expr::Attribute(ast::ExprAttribute { value, attr, .. }) = func.as_ref() {
+                    if attr == "close" {
+                        if let Expr::Name(ast::ExprName { id, .. }) = value.as_ref() {
+                            if id == self.name {
+                                self.found = true;
+                                return;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        walk_expr(self, expr);
+    }
+}
+
 /// SIM115
 pub(crate) fn open_file_with_context_handler(checker: &mut Checker, func: &Expr) {
     let semantic = checker.semantic();
@@ -174,6 +242,11 @@ pub(crate) fn open_file_with_context_handler(checker: &mut Checker, func: &Expr)
         return;
     }
 
+    // Ex) `f = open("foo.txt"); ...; f.close()`
+    if is_closed_later(semantic) {
+        return;
+    }
+
     // Ex) `with open("foo.txt") as f: ...`
     if semantic.current_statement().is_with_stmt() {
         return;
@@ -46,6 +46,7 @@ mod tests {
                 Rule::OsPathBasename,
                 Rule::OsPathDirname,
                 Rule::OsPathSamefile,
+                Rule::OsPathRelpath,
                 Rule::OsPathSplitext,
                 Rule::BuiltinOpen,
             ]),
@@ -63,6 +64,7 @@ mod tests {
     #[test_case(Rule::OsPathGetctime, Path::new("PTH205.py"))]
     #[test_case(Rule::OsSepSplit, Path::new("PTH206.py"))]
     #[test_case(Rule::Glob, Path::new("PTH207.py"))]
+    #[test_case(Rule::OsPathJoinInLoop, Path::new("PTH208.py"))]
     fn rules_pypath(rule_code: Rule, path: &Path) -> Result<()> {
         let snapshot = format!("{}_{}", rule_code.noqa_code(), path.to_string_lossy());
         let diagnostics = test_path(
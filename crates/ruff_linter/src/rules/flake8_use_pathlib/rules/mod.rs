@@ -3,6 +3,7 @@ pub(crate) use os_path_getatime::*;
 pub(crate) use os_path_getctime::*;
 pub(crate) use os_path_getmtime::*;
 pub(crate) use os_path_getsize::*;
+pub(crate) use os_path_join_in_loop::*;
 pub(crate) use os_sep_split::*;
 pub(crate) use path_constructor_current_directory::*;
 pub(crate) use replaceable_by_pathlib::*;
@@ -12,6 +13,7 @@ mod os_path_getatime;
 mod os_path_getctime;
 mod os_path_getmtime;
 mod os_path_getsize;
+mod os_path_join_in_loop;
 mod os_sep_split;
 mod path_constructor_current_directory;
 mod replaceable_by_pathlib;
@@ -0,0 +1,86 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Expr, Stmt};
+use ruff_python_semantic::Modules;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `os.path.join` calls that accumulate a path inside a loop.
+///
+/// ## Why is this bad?
+/// Repeatedly reassigning `path = os.path.join(path, segment)` builds the
+/// path one string concatenation at a time. The `pathlib` module expresses
+/// the same operation more directly with the `/` operator, or in a single
+/// call with `Path(...).joinpath(*segments)`.
+///
+/// ## Example
+/// ```python
+/// import os
+///
+/// path = base
+/// for segment in segments:
+///     path = os.path.join(path, segment)
+/// ```
+///
+/// Use instead:
+/// ```python
+/// from pathlib import Path
+///
+/// path = Path(base).joinpath(*segments)
+/// ```
+///
+/// ## References
+/// - [Python documentation: `PurePath.joinpath`](https://docs.python.org/3/library/pathlib.html#pathlib.PurePath.joinpath)
+#[violation]
+pub struct OsPathJoinInLoop;
+
+impl Violation for OsPathJoinInLoop {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!(
+            "`os.path.join` accumulation in a loop; use `Path` and `/`, or `Path.joinpath(*segments)`"
+        )
+    }
+}
+
+/// PTH208
+pub(crate) fn os_path_join_in_loop(checker: &mut Checker, assign: &ast::StmtAssign) {
+    if !checker.semantic().seen_module(Modules::OS) {
+        return;
+    }
+    let [Expr::Name(target)] = assign.targets.as_slice() else {
+        return;
+    };
+    let Expr::Call(ast::ExprCall {
+        func, arguments, ..
+    }) = assign.value.as_ref()
+    else {
+        return;
+    };
+    // The accumulator must be joined onto itself: `path = os.path.join(path, ...)`.
+    if !matches!(
+        arguments.args.first(),
+        Some(Expr::Name(first)) if first.id == target.id
+    ) {
+        return;
+    }
+    if !checker
+        .semantic()
+        .resolve_qualified_name(func)
+        .is_some_and(|qualified_name| matches!(qualified_name.segments(), ["os", "path", "join"]))
+    {
+        return;
+    }
+    if !checker
+        .semantic()
+        .current_statements()
+        .any(|stmt| matches!(stmt, Stmt::For(_) | Stmt::While(_)))
+    {
+        return;
+    }
+    checker
+        .diagnostics
+        .push(Diagnostic::new(OsPathJoinInLoop, assign.value.range()));
+}
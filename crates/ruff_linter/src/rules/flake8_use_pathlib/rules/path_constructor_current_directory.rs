@@ -1,8 +1,10 @@
 use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
 use ruff_macros::{derive_message_formats, violation};
 use ruff_python_ast::{self as ast, Expr, ExprCall};
+use ruff_text_size::Ranged;
 
 use crate::checkers::ast::Checker;
+use crate::fix::edits::{remove_argument, Parentheses};
 
 /// ## What it does
 /// Checks for `pathlib.Path` objects that are initialized with the current
@@ -10,13 +12,16 @@ use crate::checkers::ast::Checker;
 ///
 /// ## Why is this bad?
 /// The `Path()` constructor defaults to the current directory, so passing it
-/// in explicitly (as `"."`) is unnecessary.
+/// in explicitly (as `"."`) is unnecessary. Similarly, a leading `"."` or
+/// `""` segment in a multi-argument constructor (as in `Path(".", "folder")`)
+/// is dropped by `pathlib` and can be removed.
 ///
 /// ## Example
 /// ```python
 /// from pathlib import Path
 ///
 /// _ = Path(".")
+/// _ = Path(".", "folder")
 /// ```
 ///
 /// Use instead:
@@ -24,21 +29,34 @@ use crate::checkers::ast::Checker;
 /// from pathlib import Path
 ///
 /// _ = Path()
+/// _ = Path("folder")
 /// ```
 ///
 /// ## References
 /// - [Python documentation: `Path`](https://docs.python.org/3/library/pathlib.html#pathlib.Path)
 #[violation]
-pub struct PathConstructorCurrentDirectory;
+pub struct PathConstructorCurrentDirectory {
+    leading_segment: bool,
+}
 
 impl AlwaysFixableViolation for PathConstructorCurrentDirectory {
     #[derive_message_formats]
     fn message(&self) -> String {
-        format!("Do not pass the current directory explicitly to `Path`")
+        let PathConstructorCurrentDirectory { leading_segment } = self;
+        if *leading_segment {
+            format!("Do not pass a redundant current-directory segment to `Path`")
+        } else {
+            format!("Do not pass the current directory explicitly to `Path`")
+        }
     }
 
     fn fix_title(&self) -> String {
-        "Remove the current directory argument".to_string()
+        let PathConstructorCurrentDirectory { leading_segment } = self;
+        if *leading_segment {
+            "Remove the redundant leading segment".to_string()
+        } else {
+            "Remove the current directory argument".to_string()
+        }
     }
 }
 
@@ -62,13 +80,41 @@ pub(crate) fn path_constructor_current_directory(checker: &mut Checker, expr: &E
         return;
     }
 
-    let [Expr::StringLiteral(ast::ExprStringLiteral { value, range })] = &*arguments.args else {
-        return;
-    };
-
-    if matches!(value.to_str(), "" | ".") {
-        let mut diagnostic = Diagnostic::new(PathConstructorCurrentDirectory, *range);
-        diagnostic.set_fix(Fix::safe_edit(Edit::range_deletion(*range)));
-        checker.diagnostics.push(diagnostic);
+    match &*arguments.args {
+        // Ex) `Path(".")`
+        [Expr::StringLiteral(ast::ExprStringLiteral { value, range })] => {
+            if matches!(value.to_str(), "" | ".") {
+                let mut diagnostic = Diagnostic::new(
+                    PathConstructorCurrentDirectory {
+                        leading_segment: false,
+                    },
+                    *range,
+                );
+                diagnostic.set_fix(Fix::safe_edit(Edit::range_deletion(*range)));
+                checker.diagnostics.push(diagnostic);
+            }
+        }
+        // Ex) `Path(".", "folder")`
+        [first @ Expr::StringLiteral(ast::ExprStringLiteral { value, .. }), _, ..] => {
+            if matches!(value.to_str(), "" | ".") {
+                let mut diagnostic = Diagnostic::new(
+                    PathConstructorCurrentDirectory {
+                        leading_segment: true,
+                    },
+                    first.range(),
+                );
+                diagnostic.try_set_fix(|| {
+                    remove_argument(
+                        first,
+                        arguments,
+                        Parentheses::Preserve,
+                        checker.locator().contents(),
+                    )
+                    .map(Fix::safe_edit)
+                });
+                checker.diagnostics.push(diagnostic);
+            }
+        }
+        _ => {}
     }
 }
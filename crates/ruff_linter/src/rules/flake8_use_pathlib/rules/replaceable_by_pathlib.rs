@@ -1,17 +1,20 @@
-use ruff_diagnostics::{Diagnostic, DiagnosticKind};
+use ruff_diagnostics::{Diagnostic, DiagnosticKind, Edit, Fix};
 use ruff_python_ast::{Expr, ExprBooleanLiteral, ExprCall};
+use ruff_python_semantic::analyze::typing;
+use ruff_python_semantic::SemanticModel;
 use ruff_text_size::Ranged;
 
 use crate::checkers::ast::Checker;
-use crate::registry::AsRule;
+use crate::importer::ImportRequest;
+use crate::registry::{AsRule, Rule};
 use crate::rules::flake8_use_pathlib::rules::{
     Glob, OsPathGetatime, OsPathGetctime, OsPathGetmtime, OsPathGetsize,
 };
 use crate::rules::flake8_use_pathlib::violations::{
     BuiltinOpen, Joiner, OsChmod, OsGetcwd, OsMakedirs, OsMkdir, OsPathAbspath, OsPathBasename,
     OsPathDirname, OsPathExists, OsPathExpanduser, OsPathIsabs, OsPathIsdir, OsPathIsfile,
-    OsPathIslink, OsPathJoin, OsPathSamefile, OsPathSplitext, OsReadlink, OsRemove, OsRename,
-    OsReplace, OsRmdir, OsStat, OsUnlink, PyPath,
+    OsPathIslink, OsPathJoin, OsPathRelpath, OsPathSamefile, OsPathSplitext, OsReadlink, OsRemove,
+    OsRename, OsReplace, OsRmdir, OsStat, OsUnlink, PyPath,
 };
 use crate::settings::types::PythonVersion;
 
@@ -78,6 +81,13 @@ pub(crate) fn replaceable_by_pathlib(checker: &mut Checker, call: &ExprCall) {
                 }
                 .into(),
             ),
+            // PTH209
+            ["os", "path", "relpath"] => Some(
+                OsPathRelpath {
+                    has_start: call.arguments.find_argument("start", 1).is_some(),
+                }
+                .into(),
+            ),
             // PTH119
             ["os", "path", "basename"] => Some(OsPathBasename.into()),
             // PTH120
@@ -152,10 +162,134 @@ pub(crate) fn replaceable_by_pathlib(checker: &mut Checker, call: &ExprCall) {
             _ => None,
         })
     {
-        let diagnostic = Diagnostic::new::<DiagnosticKind>(diagnostic_kind, call.func.range());
+        let mut diagnostic = Diagnostic::new::<DiagnosticKind>(diagnostic_kind, call.func.range());
 
         if checker.enabled(diagnostic.kind.rule()) {
+            if diagnostic.kind.rule() == Rule::OsPathJoin {
+                if let Some(fix) = os_path_join_fix(checker, call) {
+                    diagnostic.set_fix(fix);
+                }
+            }
             checker.diagnostics.push(diagnostic);
         }
     }
 }
+
+/// Generate a [`Fix`] to rewrite `os.path.join(a, b)` as `Path(a) / b`, or
+/// `os.path.join(base, *parts)` as `Path(base).joinpath(*parts)`.
+fn os_path_join_fix(checker: &Checker, call: &ExprCall) -> Option<Fix> {
+    let semantic = checker.semantic();
+
+    // `os.sep.join` is string joining over an iterable, with no direct
+    // `pathlib` equivalent.
+    if !semantic
+        .resolve_qualified_name(&call.func)
+        .is_some_and(|qualified_name| matches!(qualified_name.segments(), ["os", "path", "join"]))
+    {
+        return None;
+    }
+
+    if !call.arguments.keywords.is_empty() {
+        return None;
+    }
+    let (first, rest) = call.arguments.args.split_first()?;
+    if first.is_starred_expr() {
+        return None;
+    }
+    // Avoid producing partially migrated expressions when an argument is
+    // itself an `os.path` call, and avoid operands that would change meaning
+    // next to the `/` operator.
+    if call.arguments.args.iter().any(|argument| {
+        let argument = match argument {
+            Expr::Starred(starred) => &starred.value,
+            _ => argument,
+        };
+        is_os_path_call(argument, semantic) || !is_slash_safe_operand(argument)
+    }) {
+        return None;
+    }
+
+    let locator = checker.locator();
+    let mut edits = Vec::new();
+    // Wrap the first argument in `Path(...)`, unless it's already a path
+    // object. `PurePath` variants are preserved rather than downgraded to a
+    // concrete `Path`.
+    let receiver = if is_path_call(first, semantic) || is_pure_path_binding(first, semantic) {
+        locator.slice(first).to_string()
+    } else {
+        let (import_edit, binding) = checker
+            .importer()
+            .get_or_import_symbol(
+                &ImportRequest::import_from("pathlib", "Path"),
+                call.start(),
+                semantic,
+            )
+            .ok()?;
+        edits.push(import_edit);
+        format!("{binding}({})", locator.slice(first))
+    };
+
+    let replacement = if call.arguments.args.iter().any(Expr::is_starred_expr) {
+        let parts = rest
+            .iter()
+            .map(|argument| locator.slice(argument))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("{receiver}.joinpath({parts})")
+    } else {
+        rest.iter().fold(receiver, |acc, argument| {
+            format!("{acc} / {}", locator.slice(argument))
+        })
+    };
+
+    Some(Fix::unsafe_edits(
+        Edit::range_replacement(replacement, call.range()),
+        edits,
+    ))
+}
+
+/// Return `true` if the expression is a call to an `os.path` function.
+fn is_os_path_call(expr: &Expr, semantic: &SemanticModel) -> bool {
+    let Expr::Call(ExprCall { func, .. }) = expr else {
+        return false;
+    };
+    semantic
+        .resolve_qualified_name(func)
+        .is_some_and(|qualified_name| matches!(qualified_name.segments(), ["os", "path", ..]))
+}
+
+/// Return `true` if the expression is a call to `pathlib.Path`.
+fn is_path_call(expr: &Expr, semantic: &SemanticModel) -> bool {
+    let Expr::Call(ExprCall { func, .. }) = expr else {
+        return false;
+    };
+    semantic
+        .resolve_qualified_name(func)
+        .is_some_and(|qualified_name| matches!(qualified_name.segments(), ["pathlib", "Path"]))
+}
+
+/// Return `true` if the expression binds at least as tightly as the `/`
+/// operator, such that it can be spliced in as an operand unparenthesized.
+fn is_slash_safe_operand(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Name(_)
+            | Expr::Attribute(_)
+            | Expr::Subscript(_)
+            | Expr::Call(_)
+            | Expr::StringLiteral(_)
+            | Expr::FString(_)
+    )
+}
+
+/// Return `true` if the expression is a variable bound to a
+/// `pathlib.PurePath` (or one of its platform-specific variants).
+fn is_pure_path_binding(expr: &Expr, semantic: &SemanticModel) -> bool {
+    let Expr::Name(name) = expr else {
+        return false;
+    };
+    semantic
+        .resolve_name(name)
+        .map(|binding_id| semantic.binding(binding_id))
+        .is_some_and(|binding| typing::is_pure_path(binding, semantic))
+}
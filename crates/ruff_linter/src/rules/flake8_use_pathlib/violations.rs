@@ -1,4 +1,4 @@
-use ruff_diagnostics::Violation;
+use ruff_diagnostics::{FixAvailability, Violation};
 use ruff_macros::{derive_message_formats, violation};
 
 /// ## What it does
@@ -822,6 +822,12 @@ impl Violation for OsPathIsabs {
 /// - [Correspondence between `os` and `pathlib`](https://docs.python.org/3/library/pathlib.html#correspondence-to-tools-in-the-os-module)
 /// - [Why you should be using pathlib](https://treyhunner.com/2018/12/why-you-should-be-using-pathlib/)
 /// - [No really, pathlib is great](https://treyhunner.com/2019/01/no-really-pathlib-is-great/)
+///
+/// ## Fix safety
+/// The fix rewrites the call to return a `Path` rather than a `str`, which
+/// may break downstream code that expects a string, and is therefore always
+/// marked as unsafe. No fix is offered when an argument is itself an
+/// `os.path` call, to avoid producing partially migrated expressions.
 #[violation]
 pub struct OsPathJoin {
     pub(crate) module: String,
@@ -829,6 +835,8 @@ pub struct OsPathJoin {
 }
 
 impl Violation for OsPathJoin {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
     #[derive_message_formats]
     fn message(&self) -> String {
         let OsPathJoin { module, joiner } = self;
@@ -841,6 +849,13 @@ impl Violation for OsPathJoin {
             }
         }
     }
+
+    fn fix_title(&self) -> Option<String> {
+        Some(match self.joiner {
+            Joiner::Slash => "Replace with `Path` and the `/` operator".to_string(),
+            Joiner::Joinpath => "Replace with `Path.joinpath()`".to_string(),
+        })
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -935,6 +950,62 @@ impl Violation for OsPathDirname {
     }
 }
 
+/// ## What it does
+/// Checks for uses of `os.path.relpath`.
+///
+/// ## Why is this bad?
+/// `pathlib` offers a high-level API for path manipulation, as compared to
+/// the lower-level API offered by `os`. When possible, using `Path` object
+/// methods such as `Path.relative_to()` can improve readability over the
+/// `os` module's counterparts (e.g., `os.path.relpath()`).
+///
+/// Note that the semantics differ slightly: `Path.relative_to()` raises
+/// `ValueError` when the path is not a subpath of the given base, while
+/// `os.path.relpath()` constructs a relative path with `..` segments. For
+/// this reason, no fix is offered.
+///
+/// ## Examples
+/// ```python
+/// import os
+///
+/// os.path.relpath("/etc/passwd", "/etc")
+/// ```
+///
+/// Use instead:
+/// ```python
+/// from pathlib import Path
+///
+/// Path("/etc/passwd").relative_to("/etc")
+/// ```
+///
+/// ## References
+/// - [Python documentation: `PurePath.relative_to`](https://docs.python.org/3/library/pathlib.html#pathlib.PurePath.relative_to)
+/// - [Python documentation: `os.path.relpath`](https://docs.python.org/3/library/os.path.html#os.path.relpath)
+/// - [PEP 428](https://peps.python.org/pep-0428/)
+/// - [Correspondence between `os` and `pathlib`](https://docs.python.org/3/library/pathlib.html#correspondence-to-tools-in-the-os-module)
+/// - [Why you should be using pathlib](https://treyhunner.com/2018/12/why-you-should-be-using-pathlib/)
+/// - [No really, pathlib is great](https://treyhunner.com/2019/01/no-really-pathlib-is-great/)
+#[violation]
+pub struct OsPathRelpath {
+    pub(crate) has_start: bool,
+}
+
+impl Violation for OsPathRelpath {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let OsPathRelpath { has_start } = self;
+        if *has_start {
+            format!(
+                "`os.path.relpath()` should be replaced by `Path.relative_to(start)` (note: `Path.relative_to()` raises for non-subpaths)"
+            )
+        } else {
+            format!(
+                "`os.path.relpath()` should be replaced by `Path.relative_to()` (note: `Path.relative_to()` raises for non-subpaths)"
+            )
+        }
+    }
+}
+
 /// ## What it does
 /// Checks for uses of `os.path.samefile`.
 ///
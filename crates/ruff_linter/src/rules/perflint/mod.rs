@@ -16,6 +16,9 @@ mod tests {
 
     #[test_case(Rule::UnnecessaryListCast, Path::new("PERF101.py"))]
     #[test_case(Rule::IncorrectDictIterator, Path::new("PERF102.py"))]
+    #[test_case(Rule::SortedConstant, Path::new("PERF103.py"))]
+    #[test_case(Rule::IterateOverRangeLen, Path::new("PERF104.py"))]
+    #[test_case(Rule::UnnecessaryCopy, Path::new("PERF105.py"))]
     #[test_case(Rule::TryExceptInLoop, Path::new("PERF203.py"))]
     #[test_case(Rule::ManualListComprehension, Path::new("PERF401.py"))]
     #[test_case(Rule::ManualListCopy, Path::new("PERF402.py"))]
@@ -0,0 +1,187 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::visitor::{walk_expr, Visitor};
+use ruff_python_ast::{self as ast, Expr};
+use ruff_text_size::{Ranged, TextRange};
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `for` loops that iterate over `range(len(...))` to index into a
+/// sequence.
+///
+/// ## Why is this bad?
+/// Iterating over `range(len(seq))` and indexing with `seq[i]` is slower and
+/// less readable than iterating over the sequence directly. If only the
+/// elements are needed, iterate over the sequence itself; if the index is
+/// also needed, use `enumerate`.
+///
+/// ## Example
+/// ```python
+/// for i in range(len(fruits)):
+///     print(fruits[i])
+/// ```
+///
+/// Use instead:
+/// ```python
+/// for fruit in fruits:
+///     print(fruit)
+/// ```
+///
+/// ## Fix safety
+/// The fix is only offered when the index variable is used exclusively to
+/// index the sequence. It is marked as unsafe, as it removes the index
+/// binding, which changes behavior if the variable is read after the loop.
+///
+/// ## References
+/// - [Python documentation: `enumerate`](https://docs.python.org/3/library/functions.html#enumerate)
+#[violation]
+pub struct IterateOverRangeLen {
+    sequence: String,
+    direct: bool,
+}
+
+impl Violation for IterateOverRangeLen {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let IterateOverRangeLen { sequence, direct } = self;
+        if *direct {
+            format!("Iterate over `{sequence}` directly instead of `range(len({sequence}))`")
+        } else {
+            format!("Use `enumerate({sequence})` instead of `range(len({sequence}))`")
+        }
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let IterateOverRangeLen { sequence, direct } = self;
+        direct.then(|| format!("Replace with iteration over `{sequence}`"))
+    }
+}
+
+/// PERF104
+pub(crate) fn iterate_over_range_len(checker: &mut Checker, for_stmt: &ast::StmtFor) {
+    if for_stmt.is_async {
+        return;
+    }
+
+    // Match `for <index> in range(len(<sequence>)):`.
+    let Expr::Name(index) = for_stmt.target.as_ref() else {
+        return;
+    };
+    let Expr::Call(range_call) = for_stmt.iter.as_ref() else {
+        return;
+    };
+    if !checker
+        .semantic()
+        .match_builtin_expr(&range_call.func, "range")
+    {
+        return;
+    }
+    if !range_call.arguments.keywords.is_empty() {
+        return;
+    }
+    let [Expr::Call(len_call)] = &*range_call.arguments.args else {
+        return;
+    };
+    if !checker.semantic().match_builtin_expr(&len_call.func, "len") {
+        return;
+    }
+    if !len_call.arguments.keywords.is_empty() {
+        return;
+    }
+    let [Expr::Name(sequence)] = &*len_call.arguments.args else {
+        return;
+    };
+
+    let mut visitor = IndexUsageVisitor {
+        index: index.id.as_str(),
+        sequence: sequence.id.as_str(),
+        subscripts: Vec::new(),
+        other_use: false,
+        mutated: false,
+    };
+    visitor.visit_body(&for_stmt.body);
+
+    // Assigning through the index (e.g., `seq[i] = ...`) requires the index.
+    if visitor.mutated {
+        return;
+    }
+
+    // If the sequence is never indexed, the loop is a plain counter.
+    if visitor.subscripts.is_empty() {
+        return;
+    }
+
+    let direct = !visitor.other_use;
+    let mut diagnostic = Diagnostic::new(
+        IterateOverRangeLen {
+            sequence: sequence.id.to_string(),
+            direct,
+        },
+        for_stmt.iter.range(),
+    );
+    if direct {
+        if let Some(element) = element_name(sequence.id.as_str(), checker) {
+            let target = Edit::range_replacement(element.clone(), for_stmt.target.range());
+            let iter = Edit::range_replacement(sequence.id.to_string(), for_stmt.iter.range());
+            let subscripts = visitor
+                .subscripts
+                .iter()
+                .map(|range| Edit::range_replacement(element.clone(), *range));
+            diagnostic.set_fix(Fix::unsafe_edits(target, subscripts.chain([iter])));
+        }
+    }
+    checker.diagnostics.push(diagnostic);
+}
+
+/// Return a name for the loop element, if one is available without shadowing
+/// an existing binding.
+fn element_name(sequence: &str, checker: &Checker) -> Option<String> {
+    let name = format!("{sequence}_item");
+    if checker.semantic().current_scope().has(&name) {
+        return None;
+    }
+    Some(name)
+}
+
+/// Collect the usages of the index variable within the loop body.
+struct IndexUsageVisitor<'a> {
+    index: &'a str,
+    sequence: &'a str,
+    /// Ranges of `sequence[index]` subscripts in a load context.
+    subscripts: Vec<TextRange>,
+    /// Whether the index is used other than to index the sequence.
+    other_use: bool,
+    /// Whether the subscript is the target of an assignment or deletion.
+    mutated: bool,
+}
+
+impl<'a> Visitor<'a> for IndexUsageVisitor<'a> {
+    fn visit_expr(&mut self, expr: &'a Expr) {
+        match expr {
+            Expr::Subscript(ast::ExprSubscript {
+                value, slice, ctx, ..
+            }) => {
+                if let (Expr::Name(value_name), Expr::Name(slice_name)) =
+                    (value.as_ref(), slice.as_ref())
+                {
+                    if value_name.id == self.sequence && slice_name.id == self.index {
+                        if ctx.is_load() {
+                            self.subscripts.push(expr.range());
+                        } else {
+                            self.mutated = true;
+                        }
+                        return;
+                    }
+                }
+                walk_expr(self, expr);
+            }
+            Expr::Name(name) if name.id == self.index => {
+                self.other_use = true;
+            }
+            _ => walk_expr(self, expr),
+        }
+    }
+}
@@ -1,10 +1,11 @@
 use ruff_python_ast::{self as ast, Arguments, Expr, Stmt};
 
-use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
 use ruff_macros::{derive_message_formats, violation};
 use ruff_python_ast::comparable::ComparableExpr;
 use ruff_python_ast::helpers::any_over_expr;
 use ruff_python_semantic::analyze::typing::is_list;
+use ruff_text_size::Ranged;
 
 use crate::checkers::ast::Checker;
 
@@ -43,12 +44,19 @@ use crate::checkers::ast::Checker;
 /// original = list(range(10000))
 /// filtered.extend(x for x in original if x % 2)
 /// ```
+///
+/// ## Fix safety
+/// The fix rewrites the loop as a comprehension passed to `extend`, which
+/// preserves any elements already in the list. It is marked as unsafe, as
+/// comments within the loop body would be removed.
 #[violation]
 pub struct ManualListComprehension {
     is_async: bool,
 }
 
 impl Violation for ManualListComprehension {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
     #[derive_message_formats]
     fn message(&self) -> String {
         let ManualListComprehension { is_async } = self;
@@ -57,6 +65,10 @@ impl Violation for ManualListComprehension {
             true => format!("Use an async list comprehension to create a transformed list"),
         }
     }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Replace the loop with a comprehension".to_string())
+    }
 }
 
 /// PERF401
@@ -188,10 +200,38 @@ pub(crate) fn manual_list_comprehension(checker: &mut Checker, for_stmt: &ast::S
         return;
     }
 
-    checker.diagnostics.push(Diagnostic::new(
+    let mut diagnostic = Diagnostic::new(
         ManualListComprehension {
             is_async: for_stmt.is_async,
         },
         *range,
-    ));
+    );
+
+    // Rewrite the loop as, e.g., `filtered.extend(x for x in y if z)`. An async
+    // comprehension can't be passed to `extend`, so only fix synchronous loops.
+    if !for_stmt.is_async {
+        let locator = checker.locator();
+        let elt = locator.slice(arg.range());
+        let iter = locator.slice(for_stmt.iter.range());
+        // Ex) `for x in a, b:` requires parentheses in a comprehension.
+        let iter = if for_stmt.iter.is_tuple_expr() && !iter.starts_with('(') {
+            format!("({iter})")
+        } else {
+            iter.to_string()
+        };
+        let contents = match if_test {
+            Some(test) => format!(
+                "{}.extend({elt} for {id} in {iter} if {})",
+                name.id,
+                locator.slice(test.range())
+            ),
+            None => format!("{}.extend({elt} for {id} in {iter})", name.id),
+        };
+        diagnostic.set_fix(Fix::unsafe_edit(Edit::range_replacement(
+            contents,
+            for_stmt.range(),
+        )));
+    }
+
+    checker.diagnostics.push(diagnostic);
 }
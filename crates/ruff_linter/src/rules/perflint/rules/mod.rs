@@ -1,13 +1,19 @@
 pub(crate) use incorrect_dict_iterator::*;
+pub(crate) use iterate_over_range_len::*;
 pub(crate) use manual_dict_comprehension::*;
 pub(crate) use manual_list_comprehension::*;
 pub(crate) use manual_list_copy::*;
+pub(crate) use sorted_constant::*;
 pub(crate) use try_except_in_loop::*;
+pub(crate) use unnecessary_copy::*;
 pub(crate) use unnecessary_list_cast::*;
 
 mod incorrect_dict_iterator;
+mod iterate_over_range_len;
 mod manual_dict_comprehension;
 mod manual_list_comprehension;
 mod manual_list_copy;
+mod sorted_constant;
 mod try_except_in_loop;
+mod unnecessary_copy;
 mod unnecessary_list_cast;
@@ -0,0 +1,189 @@
+use std::cmp::Ordering;
+
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Expr, Number, UnaryOp};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `sorted` and `reversed` calls on list or tuple literals whose
+/// elements are all constants.
+///
+/// ## Why is this bad?
+/// Sorting a literal collection of constants at runtime repeats work that
+/// could be done once, when writing the code. Pre-sorting the literal avoids
+/// the call entirely.
+///
+/// ## Example
+/// ```python
+/// for value in sorted([3, 1, 2]):
+///     ...
+/// ```
+///
+/// Use instead:
+/// ```python
+/// for value in [1, 2, 3]:
+///     ...
+/// ```
+///
+/// ## Fix safety
+/// The fix replaces a `sorted` call with the equivalent pre-sorted list
+/// literal. No fix is offered for `reversed`, which returns an iterator
+/// rather than a list.
+#[violation]
+pub struct SortedConstant {
+    function: String,
+}
+
+impl Violation for SortedConstant {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let SortedConstant { function } = self;
+        format!("`{function}` called on a constant sequence")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some(format!("Replace with the sorted literal"))
+    }
+}
+
+/// A sort key for a constant element, along with its source representation.
+enum Key<'a> {
+    Number(f64),
+    Str(&'a str),
+}
+
+impl Key<'_> {
+    fn compare(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Key::Number(left), Key::Number(right)) => left.partial_cmp(right),
+            (Key::Str(left), Key::Str(right)) => Some(left.cmp(right)),
+            _ => None,
+        }
+    }
+}
+
+/// Extract the sort key for an element, if it's a comparable constant.
+fn key_of<'a>(expr: &'a Expr) -> Option<Key<'a>> {
+    match expr {
+        Expr::NumberLiteral(ast::ExprNumberLiteral { value, .. }) => match value {
+            Number::Int(int) => {
+                let int = int.as_i64()?;
+                // Beyond 2^53, an `f64` can no longer represent every integer
+                // exactly, and comparisons could tie incorrectly.
+                if int.unsigned_abs() > (1 << 53) {
+                    return None;
+                }
+                #[allow(clippy::cast_precision_loss)]
+                Some(Key::Number(int as f64))
+            }
+            Number::Float(float) => Some(Key::Number(*float)),
+            Number::Complex { .. } => None,
+        },
+        Expr::UnaryOp(ast::ExprUnaryOp {
+            op: UnaryOp::USub,
+            operand,
+            ..
+        }) => match key_of(operand)? {
+            Key::Number(value) => Some(Key::Number(-value)),
+            Key::Str(_) => None,
+        },
+        Expr::StringLiteral(ast::ExprStringLiteral { value, .. }) => Some(Key::Str(value.to_str())),
+        _ => None,
+    }
+}
+
+/// PERF103
+pub(crate) fn sorted_constant(checker: &mut Checker, call: &ast::ExprCall) {
+    let Some(function) = ["sorted", "reversed"]
+        .into_iter()
+        .find(|function| checker.semantic().match_builtin_expr(&call.func, function))
+    else {
+        return;
+    };
+
+    let [argument] = call.arguments.args.as_ref() else {
+        return;
+    };
+
+    // A `key=` callback (or any other keyword) makes the result unknowable at
+    // lint time; only a literal `reverse=` flag on `sorted` is supported.
+    if !call
+        .arguments
+        .keywords
+        .iter()
+        .all(|keyword| function == "sorted" && keyword.arg.as_deref() == Some("reverse"))
+    {
+        return;
+    }
+
+    let reverse = match call.arguments.find_keyword("reverse") {
+        Some(keyword) => match &keyword.value {
+            Expr::BooleanLiteral(ast::ExprBooleanLiteral { value, .. }) => *value,
+            _ => return,
+        },
+        None => false,
+    };
+
+    let elts = match argument {
+        Expr::List(ast::ExprList { elts, .. }) | Expr::Tuple(ast::ExprTuple { elts, .. }) => elts,
+        _ => return,
+    };
+
+    // Every element must be a constant, and all elements must be mutually
+    // comparable (all numbers, or all strings).
+    let mut elements: Vec<(Key, &str)> = Vec::with_capacity(elts.len());
+    for elt in elts {
+        let Some(key) = key_of(elt) else {
+            return;
+        };
+        if let Some(first) = elements.first() {
+            if first.0.compare(&key).is_none() {
+                return;
+            }
+        }
+        elements.push((key, checker.locator().slice(elt)));
+    }
+
+    let mut diagnostic = Diagnostic::new(
+        SortedConstant {
+            function: function.to_string(),
+        },
+        call.range(),
+    );
+
+    // `sorted` always returns a list, so the fix is a pre-sorted list
+    // literal; `reversed` returns an iterator, for which no literal
+    // equivalent exists.
+    if function == "sorted" {
+        // Like `sorted`, keep the sort stable: with `reverse=True`, reverse
+        // the comparisons rather than the result, to preserve the original
+        // order of equal elements.
+        elements.sort_by(|(left, _), (right, _)| {
+            let ordering = left.compare(right).unwrap_or(Ordering::Equal);
+            if reverse {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+        let contents = format!(
+            "[{}]",
+            elements
+                .iter()
+                .map(|(_, source)| *source)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        diagnostic.set_fix(Fix::safe_edit(Edit::range_replacement(
+            contents,
+            call.range(),
+        )));
+    }
+
+    checker.diagnostics.push(diagnostic);
+}
@@ -0,0 +1,109 @@
+use ruff_diagnostics::{AlwaysFixableViolation, Applicability, Diagnostic, Edit, Fix};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Expr};
+use ruff_python_semantic::SemanticModel;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for copies of objects that are already freshly created, as in
+/// `copy.copy([1, 2])` or `{"a": 1}.copy()`.
+///
+/// ## Why is this bad?
+/// A literal or constructor call already produces a new object, so copying it
+/// allocates a second, identical object and discards the first. Using the
+/// fresh object directly avoids the redundant allocation.
+///
+/// Copying an existing binding is not flagged, as the copy may be intentional.
+///
+/// ## Example
+/// ```python
+/// import copy
+///
+/// values = copy.copy([1, 2])
+/// ```
+///
+/// Use instead:
+/// ```python
+/// values = [1, 2]
+/// ```
+///
+/// ## Fix safety
+/// The fix is marked as unsafe if the copy call contains comments, as they
+/// would be removed.
+#[violation]
+pub struct UnnecessaryCopy;
+
+impl AlwaysFixableViolation for UnnecessaryCopy {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("Unnecessary copy of a freshly-created object")
+    }
+
+    fn fix_title(&self) -> String {
+        "Remove the copy".to_string()
+    }
+}
+
+/// PERF105
+pub(crate) fn unnecessary_copy(checker: &mut Checker, call: &ast::ExprCall) {
+    let semantic = checker.semantic();
+
+    // Match `copy.copy(<fresh>)` or `<fresh>.copy()`.
+    let fresh = if semantic
+        .resolve_qualified_name(call.func.as_ref())
+        .is_some_and(|qualified_name| matches!(qualified_name.segments(), ["copy", "copy"]))
+    {
+        if !call.arguments.keywords.is_empty() {
+            return;
+        }
+        let [argument] = &*call.arguments.args else {
+            return;
+        };
+        if !is_fresh_object(argument, semantic) {
+            return;
+        }
+        argument
+    } else if let Expr::Attribute(ast::ExprAttribute { value, attr, .. }) = call.func.as_ref() {
+        if attr.as_str() != "copy"
+            || !call.arguments.is_empty()
+            || !is_fresh_object(value, semantic)
+        {
+            return;
+        }
+        value.as_ref()
+    } else {
+        return;
+    };
+
+    let mut diagnostic = Diagnostic::new(UnnecessaryCopy, call.range());
+    let edit = Edit::range_replacement(
+        checker.locator().slice(fresh.range()).to_string(),
+        call.range(),
+    );
+    let applicability = if checker.indexer().comment_ranges().intersects(call.range()) {
+        Applicability::Unsafe
+    } else {
+        Applicability::Safe
+    };
+    diagnostic.set_fix(Fix::applicable_edit(edit, applicability));
+    checker.diagnostics.push(diagnostic);
+}
+
+/// Return `true` if the expression is guaranteed to create a new object, like
+/// a list, dict, or set literal, comprehension, or builtin constructor call.
+fn is_fresh_object(expr: &Expr, semantic: &SemanticModel) -> bool {
+    match expr {
+        Expr::List(_)
+        | Expr::Dict(_)
+        | Expr::Set(_)
+        | Expr::ListComp(_)
+        | Expr::SetComp(_)
+        | Expr::DictComp(_) => true,
+        Expr::Call(ast::ExprCall { func, .. }) => ["dict", "list", "set"]
+            .iter()
+            .any(|builtin| semantic.match_builtin_expr(func, builtin)),
+        _ => false,
+    }
+}
@@ -176,6 +176,25 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn d407_google() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("pydocstyle/sections.py"),
+            &settings::LinterSettings {
+                // Force-enabling D407 under the Google convention must not
+                // panic on section headers that don't use underlines.
+                pydocstyle: Settings {
+                    convention: Some(Convention::Google),
+                    ignore_decorators: BTreeSet::new(),
+                    property_decorators: BTreeSet::new(),
+                },
+                ..settings::LinterSettings::for_rule(Rule::DashedUnderlineAfterSection)
+            },
+        )?;
+        assert_messages!(diagnostics);
+        Ok(())
+    }
+
     #[test]
     fn d209_d400() -> Result<()> {
         let diagnostics = test_path(
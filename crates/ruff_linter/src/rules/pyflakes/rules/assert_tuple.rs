@@ -1,6 +1,6 @@
 use ruff_python_ast::{self as ast, Expr, Stmt};
 
-use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
 use ruff_macros::{derive_message_formats, violation};
 use ruff_text_size::Ranged;
 
@@ -25,25 +25,44 @@ use crate::checkers::ast::Checker;
 /// assert some_condition
 /// ```
 ///
+/// ## Fix safety
+/// For a single-element tuple — typically the result of a stray trailing
+/// comma — a fix is offered that removes the tuple, asserting the lone
+/// element instead. The fix is marked as unsafe, as it changes the runtime
+/// behavior of the program: the always-passing assertion becomes a real one.
+///
 /// ## References
 /// - [Python documentation: The `assert` statement](https://docs.python.org/3/reference/simple_stmts.html#the-assert-statement)
 #[violation]
 pub struct AssertTuple;
 
 impl Violation for AssertTuple {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
     #[derive_message_formats]
     fn message(&self) -> String {
         format!("Assert test is a non-empty tuple, which is always `True`")
     }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Remove the tuple, asserting its element".to_string())
+    }
 }
 
 /// F631
 pub(crate) fn assert_tuple(checker: &mut Checker, stmt: &Stmt, test: &Expr) {
     if let Expr::Tuple(ast::ExprTuple { elts, .. }) = &test {
         if !elts.is_empty() {
-            checker
-                .diagnostics
-                .push(Diagnostic::new(AssertTuple, stmt.range()));
+            let mut diagnostic = Diagnostic::new(AssertTuple, stmt.range());
+            // A single-element tuple is typically a stray trailing comma;
+            // assert the lone element instead.
+            if let [element] = elts.as_slice() {
+                diagnostic.set_fix(Fix::unsafe_edit(Edit::range_replacement(
+                    checker.locator().slice(element).to_string(),
+                    test.range(),
+                )));
+            }
+            checker.diagnostics.push(diagnostic);
         }
     }
 }
@@ -2,10 +2,10 @@ use std::string::ToString;
 
 use rustc_hash::FxHashSet;
 
-use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Fix, FixAvailability, Violation};
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix, FixAvailability, Violation};
 use ruff_macros::{derive_message_formats, violation};
 use ruff_python_ast::{self as ast, Expr, Identifier, Keyword};
-use ruff_text_size::{Ranged, TextRange};
+use ruff_text_size::{Ranged, TextLen, TextRange, TextSize};
 
 use crate::checkers::ast::Checker;
 
@@ -315,6 +315,12 @@ impl Violation for PercentFormatStarRequiresSequence {
 /// "Hello, %s" % "world"
 /// ```
 ///
+/// ## Fix safety
+/// When the unsupported character differs only in case from a supported
+/// format character (e.g., `%S` instead of `%s`), a fix is offered to
+/// lowercase it. The fix is marked as unsafe, as the lowercase character may
+/// not be the intended one.
+///
 /// ## References
 /// - [Python documentation: `printf`-style String Formatting](https://docs.python.org/3/library/stdtypes.html#printf-style-string-formatting)
 #[violation]
@@ -323,11 +329,19 @@ pub struct PercentFormatUnsupportedFormatCharacter {
 }
 
 impl Violation for PercentFormatUnsupportedFormatCharacter {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
     #[derive_message_formats]
     fn message(&self) -> String {
         let PercentFormatUnsupportedFormatCharacter { char } = self;
         format!("`%`-format string has unsupported format character `{char}`")
     }
+
+    fn fix_title(&self) -> Option<String> {
+        let PercentFormatUnsupportedFormatCharacter { char } = self;
+        supported_case_counterpart(*char)
+            .map(|supported| format!("Replace `%{char}` with `%{supported}`"))
+    }
 }
 
 /// ## What it does
@@ -727,6 +741,67 @@ pub(crate) fn percent_format_star_requires_sequence(
     }
 }
 
+/// F509
+pub(crate) fn percent_format_unsupported_format_character(
+    checker: &mut Checker,
+    string: &Expr,
+    value: &ast::StringLiteralValue,
+    char: char,
+    index: usize,
+    location: TextRange,
+) {
+    let mut diagnostic =
+        Diagnostic::new(PercentFormatUnsupportedFormatCharacter { char }, location);
+    if let Some(supported) = supported_case_counterpart(char) {
+        if let Some(range) =
+            format_character_range(checker.locator().slice(string), value.to_str(), index, char)
+        {
+            diagnostic.set_fix(Fix::unsafe_edit(Edit::range_replacement(
+                supported.to_string(),
+                range + string.start(),
+            )));
+        }
+    }
+    checker.diagnostics.push(diagnostic);
+}
+
+/// Return the supported format character that differs only in case from the
+/// given unsupported format character, if any.
+fn supported_case_counterpart(char: char) -> Option<char> {
+    let lowercase = char.to_ascii_lowercase();
+    if char.is_ascii_uppercase()
+        && matches!(
+            lowercase,
+            'd' | 'i' | 'u' | 'o' | 'x' | 'e' | 'f' | 'g' | 'c' | 'r' | 's' | 'b' | 'a'
+        )
+    {
+        Some(lowercase)
+    } else {
+        None
+    }
+}
+
+/// Return the range of the format character within the source of a string
+/// expression, given its index in the string's value.
+///
+/// Returns `None` if the value doesn't appear verbatim in the source (e.g.,
+/// due to escape sequences or implicit concatenation), in which case the index
+/// can't be mapped onto the source.
+fn format_character_range(
+    source: &str,
+    value: &str,
+    index: usize,
+    char: char,
+) -> Option<TextRange> {
+    let offset = source.find(value)?;
+    let (char_offset, c) = value.char_indices().nth(index)?;
+    if c != char {
+        return None;
+    }
+    let start = TextSize::try_from(offset + char_offset).ok()?;
+    Some(TextRange::at(start, char.text_len()))
+}
+
 /// F522
 pub(crate) fn string_dot_format_extra_named_arguments(
     checker: &mut Checker,
@@ -69,6 +69,7 @@ mod tests {
         Path::new("global_variable_not_assigned.py")
     )]
     #[test_case(Rule::ImportOutsideTopLevel, Path::new("import_outside_top_level.py"))]
+    #[test_case(Rule::InconsistentReturns, Path::new("inconsistent_returns.py"))]
     #[test_case(
         Rule::ImportPrivateName,
         Path::new("import_private_name/submodule/__main__.py")
@@ -103,6 +104,7 @@ mod tests {
     #[test_case(Rule::InvalidEnvvarDefault, Path::new("invalid_envvar_default.py"))]
     #[test_case(Rule::InvalidEnvvarValue, Path::new("invalid_envvar_value.py"))]
     #[test_case(Rule::IterationOverSet, Path::new("iteration_over_set.py"))]
+    #[test_case(Rule::LenTest, Path::new("len_as_condition.py"))]
     #[test_case(Rule::LoggingTooFewArgs, Path::new("logging_too_few_args.py"))]
     #[test_case(Rule::LoggingTooManyArgs, Path::new("logging_too_many_args.py"))]
     #[test_case(Rule::MagicValueComparison, Path::new("magic_value_comparison.py"))]
@@ -266,6 +268,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn min_repeated_comparisons() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("pylint/repeated_equality_comparison.py"),
+            &LinterSettings {
+                pylint: pylint::settings::Settings {
+                    min_repeated_comparisons: 3,
+                    ..pylint::settings::Settings::default()
+                },
+                ..LinterSettings::for_rule(Rule::RepeatedEqualityComparison)
+            },
+        )?;
+        assert_messages!(diagnostics);
+        Ok(())
+    }
+
     #[test]
     fn max_args() -> Result<()> {
         let diagnostics = test_path(
@@ -19,12 +19,19 @@ enum Kind {
 ///
 /// ## Why is this bad?
 /// An `assert` on a non-empty string literal will always pass, while an
-/// `assert` on an empty string literal will always fail.
+/// `assert` on an empty string literal will always fail. In either case, the
+/// string was likely intended as the assertion's _message_, with the actual
+/// condition omitted by mistake.
 ///
 /// ## Example
 /// ```python
 /// assert "always true"
 /// ```
+///
+/// Use instead:
+/// ```python
+/// assert condition, "message if the condition fails"
+/// ```
 #[violation]
 pub struct AssertOnStringLiteral {
     kind: Kind,
@@ -1,4 +1,4 @@
-use ruff_diagnostics::Violation;
+use ruff_diagnostics::{FixAvailability, Violation};
 use ruff_macros::{derive_message_formats, violation};
 
 /// ## What it does
@@ -41,9 +41,15 @@ pub struct GlobalVariableNotAssigned {
 }
 
 impl Violation for GlobalVariableNotAssigned {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
     #[derive_message_formats]
     fn message(&self) -> String {
         let GlobalVariableNotAssigned { name } = self;
         format!("Using global for `{name}` but no assignment is done")
     }
+
+    fn fix_title(&self) -> Option<String> {
+        Some(format!("Remove `global` statement"))
+    }
 }
@@ -0,0 +1,119 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::helpers::ReturnStatementVisitor;
+use ruff_python_ast::identifier::Identifier;
+use ruff_python_ast::visitor::Visitor;
+use ruff_python_ast::{self as ast, Expr, Operator};
+use ruff_python_semantic::analyze::terminal::Terminal;
+use ruff_python_semantic::SemanticModel;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for functions that return a value in some branches, but return
+/// implicitly (via a bare `return` or by falling off the end) in others.
+///
+/// ## Why is this bad?
+/// An implicit return evaluates to `None`. Mixing explicit value returns with
+/// implicit ones usually indicates a forgotten `return` in one branch, and
+/// forces every caller to handle an undocumented `None`.
+///
+/// If returning `None` is intentional, make it explicit with `return None`,
+/// and annotate the function as returning an optional type.
+///
+/// ## Example
+/// ```python
+/// def find(items, key):
+///     for item in items:
+///         if item.key == key:
+///             return item
+/// ```
+///
+/// Use instead:
+/// ```python
+/// def find(items, key):
+///     for item in items:
+///         if item.key == key:
+///             return item
+///     return None
+/// ```
+///
+/// ## References
+/// - [Python documentation: `return`](https://docs.python.org/3/reference/simple_stmts.html#the-return-statement)
+#[violation]
+pub struct InconsistentReturns;
+
+impl Violation for InconsistentReturns {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!(
+            "Either all return statements in a function should return an expression, or none of them should"
+        )
+    }
+}
+
+/// Returns `true` if the annotation explicitly allows `None`, in which case
+/// mixing value and valueless returns is presumed intentional.
+fn annotation_allows_none(annotation: &Expr, semantic: &SemanticModel) -> bool {
+    match annotation {
+        Expr::NoneLiteral(_) => true,
+        // Ex) `int | None`
+        Expr::BinOp(ast::ExprBinOp {
+            op: Operator::BitOr,
+            left,
+            right,
+            ..
+        }) => annotation_allows_none(left, semantic) || annotation_allows_none(right, semantic),
+        // Ex) `Optional[int]`, `Union[int, None]`
+        Expr::Subscript(ast::ExprSubscript { value, slice, .. }) => {
+            if semantic.match_typing_expr(value, "Optional") {
+                return true;
+            }
+            if semantic.match_typing_expr(value, "Union") {
+                if let Expr::Tuple(tuple) = slice.as_ref() {
+                    return tuple
+                        .elts
+                        .iter()
+                        .any(|elt| annotation_allows_none(elt, semantic));
+                }
+                return annotation_allows_none(slice, semantic);
+            }
+            false
+        }
+        // A string annotation can't be analyzed here; assume it's intentional.
+        Expr::StringLiteral(_) => true,
+        expr => semantic.match_typing_expr(expr, "Any"),
+    }
+}
+
+/// PLR1710
+pub(crate) fn inconsistent_returns(checker: &mut Checker, function_def: &ast::StmtFunctionDef) {
+    if let Some(returns) = function_def.returns.as_deref() {
+        if annotation_allows_none(returns, checker.semantic()) {
+            return;
+        }
+    }
+
+    let (returns, is_generator) = {
+        let mut visitor = ReturnStatementVisitor::default();
+        visitor.visit_body(&function_def.body);
+        (visitor.returns, visitor.is_generator)
+    };
+
+    // In a generator, `return` is a `StopIteration` rather than a value.
+    if is_generator {
+        return;
+    }
+
+    if !returns.iter().any(|stmt| stmt.value.is_some()) {
+        return;
+    }
+
+    let has_bare_return = returns.iter().any(|stmt| stmt.value.is_none());
+    if has_bare_return || Terminal::from_function(function_def).has_implicit_return() {
+        checker.diagnostics.push(Diagnostic::new(
+            InconsistentReturns,
+            function_def.identifier(),
+        ));
+    }
+}
@@ -0,0 +1,133 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Expr, Stmt, UnaryOp};
+use ruff_python_semantic::SemanticModel;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `len` calls on sequences in a boolean test context.
+///
+/// ## Why is this bad?
+/// Empty sequences are considered false in a boolean context. You can either
+/// remove the call to `len` (`if not len(x)` becomes `if not x`), or compare
+/// the length against a scalar (`if len(x) > 0`), but using `len(x)` directly
+/// as a condition is both slower and less readable than relying on the
+/// sequence's truthiness.
+///
+/// ## Example
+/// ```python
+/// fruits = ["orange", "apple"]
+///
+/// if len(fruits):
+///     print(fruits)
+/// ```
+///
+/// Use instead:
+/// ```python
+/// fruits = ["orange", "apple"]
+///
+/// if fruits:
+///     print(fruits)
+/// ```
+///
+/// ## Fix safety
+/// This fix is marked as unsafe, as an object can define `__bool__` to
+/// diverge from the truthiness implied by `__len__`, in which case removing
+/// the `len` call would change the behavior of the condition.
+///
+/// ## References
+/// - [Python documentation: Truth Value Testing](https://docs.python.org/3/library/stdtypes.html#truth-value-testing)
+#[violation]
+pub struct LenTest;
+
+impl Violation for LenTest {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("`len(SEQUENCE)` used as condition without comparison")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some(format!("Remove `len`"))
+    }
+}
+
+/// Returns `true` if the call is used as a boolean test: the condition of an
+/// `if`/`elif`/`while`/`assert` or conditional expression, an operand of a
+/// boolean operator or `not`, or a comprehension filter.
+fn is_boolean_test(call: &ast::ExprCall, semantic: &SemanticModel) -> bool {
+    let range = call.range();
+    match semantic.current_expression_parent() {
+        Some(Expr::BoolOp(_)) => true,
+        Some(Expr::UnaryOp(ast::ExprUnaryOp {
+            op: UnaryOp::Not, ..
+        })) => true,
+        Some(Expr::If(ast::ExprIf { test, .. })) => test.range() == range,
+        Some(
+            Expr::ListComp(ast::ExprListComp { generators, .. })
+            | Expr::SetComp(ast::ExprSetComp { generators, .. })
+            | Expr::DictComp(ast::ExprDictComp { generators, .. })
+            | Expr::Generator(ast::ExprGenerator { generators, .. }),
+        ) => generators
+            .iter()
+            .any(|comprehension| comprehension.ifs.iter().any(|test| test.range() == range)),
+        Some(_) => false,
+        None => match semantic.current_statement() {
+            Stmt::If(ast::StmtIf {
+                test,
+                elif_else_clauses,
+                ..
+            }) => {
+                test.range() == range
+                    || elif_else_clauses.iter().any(|clause| {
+                        clause
+                            .test
+                            .as_ref()
+                            .is_some_and(|test| test.range() == range)
+                    })
+            }
+            Stmt::While(ast::StmtWhile { test, .. }) => test.range() == range,
+            Stmt::Assert(ast::StmtAssert { test, .. }) => test.range() == range,
+            _ => false,
+        },
+    }
+}
+
+/// PLC1802
+pub(crate) fn len_test(checker: &mut Checker, call: &ast::ExprCall) {
+    if !is_boolean_test(call, checker.semantic()) {
+        return;
+    }
+
+    let ast::ExprCall {
+        func, arguments, ..
+    } = call;
+
+    if !checker.semantic().match_builtin_expr(func, "len") {
+        return;
+    }
+
+    // Single positional argument, no keywords.
+    let Some(argument) = arguments.find_positional(0) else {
+        return;
+    };
+    if arguments.len() != 1 {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(LenTest, call.range());
+    // Only offer a fix when dropping the parentheses can't change precedence.
+    if matches!(
+        argument,
+        Expr::Name(_) | Expr::Attribute(_) | Expr::Subscript(_) | Expr::Call(_)
+    ) {
+        diagnostic.set_fix(Fix::unsafe_edit(Edit::range_replacement(
+            checker.locator().slice(argument).to_string(),
+            call.range(),
+        )));
+    }
+    checker.diagnostics.push(diagnostic);
+}
@@ -25,6 +25,7 @@ pub(crate) use if_stmt_min_max::*;
 pub(crate) use import_outside_top_level::*;
 pub(crate) use import_private_name::*;
 pub(crate) use import_self::*;
+pub(crate) use inconsistent_returns::*;
 pub(crate) use invalid_all_format::*;
 pub(crate) use invalid_all_object::*;
 pub(crate) use invalid_bool_return::*;
@@ -37,6 +38,7 @@ pub(crate) use invalid_length_return::*;
 pub(crate) use invalid_str_return::*;
 pub(crate) use invalid_string_characters::*;
 pub(crate) use iteration_over_set::*;
+pub(crate) use len_test::*;
 pub(crate) use literal_membership::*;
 pub(crate) use load_before_global_declaration::*;
 pub(crate) use logging::*;
@@ -127,6 +129,7 @@ mod if_stmt_min_max;
 mod import_outside_top_level;
 mod import_private_name;
 mod import_self;
+mod inconsistent_returns;
 mod invalid_all_format;
 mod invalid_all_object;
 mod invalid_bool_return;
@@ -139,6 +142,7 @@ mod invalid_length_return;
 mod invalid_str_return;
 mod invalid_string_characters;
 mod iteration_over_set;
+mod len_test;
 mod literal_membership;
 mod load_before_global_declaration;
 mod logging;
@@ -1,6 +1,6 @@
 use ruff_diagnostics::{Diagnostic, Violation};
 use ruff_macros::{derive_message_formats, violation};
-use ruff_python_ast::{self as ast, Expr};
+use ruff_python_ast::{self as ast, CmpOp, Expr};
 use ruff_python_semantic::SemanticModel;
 use ruff_text_size::Ranged;
 
@@ -17,6 +17,9 @@ use crate::checkers::ast::Checker;
 /// To determine whether a value is `NaN`, use `math.isnan` or `np.isnan`
 /// instead of comparing against `NaN` directly.
 ///
+/// Only equality comparisons (`==` and `!=`) are flagged; identity and
+/// ordering comparisons have well-defined (if rarely useful) semantics.
+///
 /// ## Example
 /// ```python
 /// if x == float("NaN"):
@@ -48,8 +51,26 @@ impl Violation for NanComparison {
 }
 
 /// PLW0177
-pub(crate) fn nan_comparison(checker: &mut Checker, left: &Expr, comparators: &[Expr]) {
-    for expr in std::iter::once(left).chain(comparators.iter()) {
+pub(crate) fn nan_comparison(checker: &mut Checker, compare: &ast::ExprCompare) {
+    let ops = &compare.ops;
+    for (index, expr) in std::iter::once(compare.left.as_ref())
+        .chain(compare.comparators.iter())
+        .enumerate()
+    {
+        // Only flag operands of an `==` or `!=` comparison: each operand is
+        // the right-hand side of the operator before it and the left-hand
+        // side of the operator after it.
+        let is_equality_operand = index
+            .checked_sub(1)
+            .and_then(|previous| ops.get(previous))
+            .is_some_and(|op| matches!(op, CmpOp::Eq | CmpOp::NotEq))
+            || ops
+                .get(index)
+                .is_some_and(|op| matches!(op, CmpOp::Eq | CmpOp::NotEq));
+        if !is_equality_operand {
+            continue;
+        }
+
         if let Some(qualified_name) = checker.semantic().resolve_qualified_name(expr) {
             match qualified_name.segments() {
                 ["numpy", "nan" | "NAN" | "NaN"] => {
@@ -42,6 +42,9 @@ use crate::fix::snippet::SourceCodeSnippet;
 /// foo in {"bar", "baz", "qux"}
 /// ```
 ///
+/// ## Options
+/// - `lint.pylint.min-repeated-comparisons`
+///
 /// ## References
 /// - [Python documentation: Comparisons](https://docs.python.org/3/reference/expressions.html#comparisons)
 /// - [Python documentation: Membership test operations](https://docs.python.org/3/reference/expressions.html#membership-test-operations)
@@ -121,7 +124,7 @@ pub(crate) fn repeated_equality_comparison(checker: &mut Checker, bool_op: &ast:
         .iter()
         .sorted_by_key(|(_, (start, _))| *start)
     {
-        if comparators.len() > 1 {
+        if comparators.len() >= checker.settings.pylint.min_repeated_comparisons {
             let mut diagnostic = Diagnostic::new(
                 RepeatedEqualityComparison {
                     expression: SourceCodeSnippet::new(merged_membership_test(
@@ -1,6 +1,6 @@
 use ruff_diagnostics::{AlwaysFixableViolation, Applicability, Diagnostic, Fix};
 use ruff_macros::{derive_message_formats, violation};
-use ruff_python_ast as ast;
+use ruff_python_ast::{self as ast, Expr, Stmt};
 use ruff_python_semantic::Modules;
 use ruff_text_size::Ranged;
 
@@ -17,6 +17,9 @@ use crate::fix::edits::add_argument;
 /// Instead, consider using `check=True` to raise an exception if the process
 /// fails, or set `check=False` explicitly to mark the behavior as intentional.
 ///
+/// Calls whose result is assigned to a name are exempt, as binding the
+/// returned `CompletedProcess` suggests that the return code is inspected.
+///
 /// ## Example
 /// ```python
 /// import subprocess
@@ -70,6 +73,22 @@ pub(crate) fn subprocess_run_without_check(checker: &mut Checker, call: &ast::Ex
         .resolve_qualified_name(&call.func)
         .is_some_and(|qualified_name| matches!(qualified_name.segments(), ["subprocess", "run"]))
     {
+        // If the result is bound to a name, assume that the caller inspects
+        // the returned `CompletedProcess` (e.g., its `returncode`).
+        if checker.semantic().current_expression_parent().is_none() {
+            match checker.semantic().current_statement() {
+                Stmt::Assign(ast::StmtAssign { targets, .. })
+                    if targets.iter().all(Expr::is_name_expr) =>
+                {
+                    return;
+                }
+                Stmt::AnnAssign(ast::StmtAnnAssign { target, .. }) if target.is_name_expr() => {
+                    return;
+                }
+                _ => {}
+            }
+        }
+
         if call.arguments.find_keyword("check").is_none() {
             let mut diagnostic = Diagnostic::new(SubprocessRunWithoutCheck, call.func.range());
             diagnostic.set_fix(Fix::applicable_edit(
@@ -2,6 +2,7 @@ use ruff_python_ast::{self as ast, ExceptHandler, Stmt};
 
 use ruff_diagnostics::{Diagnostic, Violation};
 use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::helpers::is_docstring_stmt;
 use ruff_python_ast::identifier::Identifier;
 
 /// ## What it does
@@ -62,6 +63,14 @@ impl Violation for TooManyStatements {
     }
 }
 
+/// Like Pylint, exclude a function's docstring from its statement count.
+fn skip_docstring(body: &[Stmt]) -> &[Stmt] {
+    match body {
+        [first, rest @ ..] if is_docstring_stmt(first) => rest,
+        _ => body,
+    }
+}
+
 fn num_statements(stmts: &[Stmt]) -> usize {
     let mut count = 0;
     for stmt in stmts {
@@ -120,8 +129,11 @@ fn num_statements(stmts: &[Stmt]) -> usize {
                     count += num_statements(body);
                 }
             }
-            Stmt::FunctionDef(ast::StmtFunctionDef { body, .. })
-            | Stmt::With(ast::StmtWith { body, .. }) => {
+            Stmt::FunctionDef(ast::StmtFunctionDef { body, .. }) => {
+                count += 1;
+                count += num_statements(skip_docstring(body));
+            }
+            Stmt::With(ast::StmtWith { body, .. }) => {
                 count += 1;
                 count += num_statements(body);
             }
@@ -140,7 +152,7 @@ pub(crate) fn too_many_statements(
     body: &[Stmt],
     max_statements: usize,
 ) -> Option<Diagnostic> {
-    let statements = num_statements(body);
+    let statements = num_statements(skip_docstring(body));
     if statements > max_statements {
         Some(Diagnostic::new(
             TooManyStatements {
@@ -172,6 +184,18 @@ def f():
         Ok(())
     }
 
+    #[test]
+    fn docstring() -> Result<()> {
+        let source: &str = r#"
+def f():
+    """A docstring, which doesn't count as a statement."""
+    pass
+"#;
+        let stmts = parse_suite(source)?;
+        assert_eq!(num_statements(&stmts), 2);
+        Ok(())
+    }
+
     #[test]
     fn if_else() -> Result<()> {
         let source: &str = r"
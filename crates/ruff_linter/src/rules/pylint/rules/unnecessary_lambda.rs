@@ -85,13 +85,22 @@ pub(crate) fn unnecessary_lambda(checker: &mut Checker, lambda: &ExprLambda) {
     // function.
     if parameters.as_ref().is_some_and(|parameters| {
         parameters
-            .args
-            .iter()
+            .iter_non_variadic_params()
             .any(|ParameterWithDefault { default, .. }| default.is_some())
     }) {
         return;
     }
 
+    // Keyword-only parameters are never forwarded by the checks below, so a lambda that
+    // declares them (e.g., `lambda *args, x, **kwargs: f(*args, **kwargs)`) can't be inlined
+    // without changing how keyword arguments are bound.
+    if parameters
+        .as_ref()
+        .is_some_and(|parameters| !parameters.kwonlyargs.is_empty())
+    {
+        return;
+    }
+
     match parameters.as_ref() {
         None => {
             if !arguments.is_empty() {
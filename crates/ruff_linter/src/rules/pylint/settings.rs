@@ -61,6 +61,7 @@ pub struct Settings {
     pub max_public_methods: usize,
     pub max_locals: usize,
     pub max_nested_blocks: usize,
+    pub min_repeated_comparisons: usize,
 }
 
 impl Default for Settings {
@@ -77,6 +78,7 @@ impl Default for Settings {
             max_public_methods: 20,
             max_locals: 15,
             max_nested_blocks: 5,
+            min_repeated_comparisons: 2,
         }
     }
 }
@@ -96,7 +98,8 @@ impl fmt::Display for Settings {
                 self.max_branches,
                 self.max_statements,
                 self.max_public_methods,
-                self.max_locals
+                self.max_locals,
+                self.min_repeated_comparisons
             ]
         }
         Ok(())
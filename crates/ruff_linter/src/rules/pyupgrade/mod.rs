@@ -62,6 +62,7 @@ mod tests {
     #[test_case(Rule::SuperCallWithParameters, Path::new("UP008.py"))]
     #[test_case(Rule::TimeoutErrorAlias, Path::new("UP041.py"))]
     #[test_case(Rule::ReplaceStrEnum, Path::new("UP042.py"))]
+    #[test_case(Rule::DictMergeWithPipe, Path::new("UP043.py"))]
     #[test_case(Rule::TypeOfPrimitive, Path::new("UP003.py"))]
     #[test_case(Rule::TypingTextStrAlias, Path::new("UP019.py"))]
     #[test_case(Rule::UTF8EncodingDeclaration, Path::new("UP009_0.py"))]
@@ -110,6 +111,19 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn dict_merge_with_pipe_not_applied_py38() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("pyupgrade/UP043.py"),
+            &settings::LinterSettings {
+                target_version: PythonVersion::Py38,
+                ..settings::LinterSettings::for_rule(Rule::DictMergeWithPipe)
+            },
+        )?;
+        assert_messages!(diagnostics);
+        Ok(())
+    }
+
     #[test]
     fn non_pep695_type_alias_not_applied_py311() -> Result<()> {
         let diagnostics = test_path(
@@ -0,0 +1,90 @@
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Expr};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+use crate::settings::types::PythonVersion;
+
+/// ## What it does
+/// Checks for dict literals that merge dictionaries via `**`-unpacking, like
+/// `{**a, **b}`.
+///
+/// ## Why is this bad?
+/// Python 3.9 introduced the `|` operator for merging dictionaries ([PEP
+/// 584]). `a | b` expresses the merge directly, without constructing an
+/// intermediate literal.
+///
+/// ## Example
+/// ```python
+/// merged = {**a, **b}
+/// ```
+///
+/// Use instead:
+/// ```python
+/// merged = a | b
+/// ```
+///
+/// ## Fix safety
+/// This rule's fix is marked as unsafe, as `**`-unpacking accepts any
+/// mapping, while the `|` operator requires both operands to be `dict` (or to
+/// implement `__or__`).
+///
+/// [PEP 584]: https://peps.python.org/pep-0584/
+#[violation]
+pub struct DictMergeWithPipe;
+
+impl AlwaysFixableViolation for DictMergeWithPipe {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("Dictionary merge via `**`-unpacking")
+    }
+
+    fn fix_title(&self) -> String {
+        "Replace with `|` operator".to_string()
+    }
+}
+
+/// Returns `true` if the expression can appear as a `|` operand without
+/// parentheses.
+fn is_pipe_atom(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Name(_) | Expr::Attribute(_) | Expr::Subscript(_) | Expr::Call(_) | Expr::Dict(_)
+    )
+}
+
+/// UP043
+pub(crate) fn dict_merge_with_pipe(checker: &mut Checker, dict: &ast::ExprDict) {
+    if checker.settings.target_version < PythonVersion::Py39 {
+        return;
+    }
+
+    // Only flag literals that consist exclusively of two or more
+    // `**`-spreads; interleaved key-value pairs would change the merge
+    // semantics under reordering.
+    if dict.items.len() < 2 || !dict.items.iter().all(|item| item.key.is_none()) {
+        return;
+    }
+
+    let contents = dict
+        .items
+        .iter()
+        .map(|item| {
+            let source = checker.locator().slice(&item.value);
+            if is_pipe_atom(&item.value) {
+                source.to_string()
+            } else {
+                format!("({source})")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" | ");
+
+    let mut diagnostic = Diagnostic::new(DictMergeWithPipe, dict.range());
+    diagnostic.set_fix(Fix::unsafe_edit(Edit::range_replacement(
+        contents,
+        dict.range(),
+    )));
+    checker.diagnostics.push(diagnostic);
+}
@@ -5,6 +5,7 @@ pub(crate) use deprecated_c_element_tree::*;
 pub(crate) use deprecated_import::*;
 pub(crate) use deprecated_mock_import::*;
 pub(crate) use deprecated_unittest_alias::*;
+pub(crate) use dict_merge_with_pipe::*;
 pub(crate) use extraneous_parentheses::*;
 pub(crate) use f_strings::*;
 pub(crate) use format_literals::*;
@@ -46,6 +47,7 @@ mod deprecated_c_element_tree;
 mod deprecated_import;
 mod deprecated_mock_import;
 mod deprecated_unittest_alias;
+mod dict_merge_with_pipe;
 mod extraneous_parentheses;
 mod f_strings;
 mod format_literals;
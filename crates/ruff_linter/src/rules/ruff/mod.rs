@@ -1,6 +1,7 @@
 //! Ruff-specific rules.
 
 pub(crate) mod rules;
+pub mod settings;
 pub(crate) mod typing;
 
 #[cfg(test)]
@@ -54,6 +55,52 @@ mod tests {
     #[test_case(Rule::MissingFStringSyntax, Path::new("RUF027_2.py"))]
     #[test_case(Rule::InvalidFormatterSuppressionComment, Path::new("RUF028.py"))]
     #[test_case(Rule::UnusedAsync, Path::new("RUF029.py"))]
+    #[test_case(Rule::MisannotatedGenerator, Path::new("RUF030.py"))]
+    #[test_case(Rule::EnvVarTruthiness, Path::new("RUF031.py"))]
+    #[test_case(Rule::AssertMessageSideEffect, Path::new("RUF032.py"))]
+    #[test_case(Rule::DuplicateDecorator, Path::new("RUF033.py"))]
+    #[test_case(Rule::MultipleWithItemsNeedsParens, Path::new("RUF034.py"))]
+    #[test_case(Rule::WalrusInAssertMessage, Path::new("RUF035.py"))]
+    #[test_case(Rule::InconsistentOptionalStyle, Path::new("RUF036.py"))]
+    #[test_case(Rule::InconsistentOptionalStyle, Path::new("RUF036_1.py"))]
+    #[test_case(Rule::FStringDebugSpecifier, Path::new("RUF037.py"))]
+    #[test_case(Rule::WrongDecoratorOrder, Path::new("RUF038.py"))]
+    #[test_case(Rule::UnnecessaryReturnAwait, Path::new("RUF039.py"))]
+    #[test_case(Rule::HasattrThenGetattr, Path::new("RUF040.py"))]
+    #[test_case(Rule::AssignmentFromSortInPlace, Path::new("RUF041.py"))]
+    #[test_case(Rule::ImplicitStringConcatenationPreferred, Path::new("RUF042.py"))]
+    #[test_case(Rule::RedundantTypeConversion, Path::new("RUF043.py"))]
+    #[test_case(Rule::FloatEqualityComparison, Path::new("RUF044.py"))]
+    #[test_case(Rule::PathJoinWithAbsolute, Path::new("RUF045.py"))]
+    #[test_case(Rule::RedundantParenthesesOnReturn, Path::new("RUF046.py"))]
+    #[test_case(Rule::WallClockElapsedTime, Path::new("RUF047.py"))]
+    #[test_case(Rule::DeeplyNestedFString, Path::new("RUF048.py"))]
+    #[test_case(Rule::AwaitNonAwaitable, Path::new("RUF049.py"))]
+    #[test_case(Rule::PrintToStderr, Path::new("RUF050.py"))]
+    #[test_case(Rule::AssertTypeEquality, Path::new("RUF051.py"))]
+    #[test_case(Rule::RedefinedDunderAll, Path::new("RUF052.py"))]
+    #[test_case(Rule::GeneratorWhereListNeeded, Path::new("RUF053.py"))]
+    #[test_case(Rule::BytesStrComparison, Path::new("RUF054.py"))]
+    #[test_case(Rule::AsyncioGatherSwallowsExceptions, Path::new("RUF055.py"))]
+    #[test_case(Rule::SlotsWithoutAllBasesSlotted, Path::new("RUF056.py"))]
+    #[test_case(Rule::ReducibleReduce, Path::new("RUF057.py"))]
+    #[test_case(Rule::ConditionalImportWithoutFallback, Path::new("RUF058.py"))]
+    #[test_case(Rule::SideEffectInComprehension, Path::new("RUF059.py"))]
+    #[test_case(Rule::UnnecessaryListInJoin, Path::new("RUF060.py"))]
+    #[test_case(Rule::AssertDictSetEquality, Path::new("RUF061.py"))]
+    #[test_case(Rule::StaticMethodCouldBeFunction, Path::new("RUF062.py"))]
+    #[test_case(Rule::MissingSuperInitCall, Path::new("RUF063.py"))]
+    #[test_case(Rule::RedundantUtf8Codec, Path::new("RUF064.py"))]
+    #[test_case(Rule::RedundantBooleanComparison, Path::new("RUF065.py"))]
+    #[test_case(Rule::DeleteLoopVariable, Path::new("RUF066.py"))]
+    #[test_case(Rule::ExitReturnsTruthy, Path::new("RUF067.py"))]
+    #[test_case(Rule::ConflictingConditionalImport, Path::new("RUF068.py"))]
+    #[test_case(Rule::DecoratorMissingWraps, Path::new("RUF069.py"))]
+    #[test_case(Rule::YieldInsideContextManager, Path::new("RUF070.py"))]
+    #[test_case(Rule::NonExhaustiveMatch, Path::new("RUF071.py"))]
+    #[test_case(Rule::IncompleteContextManagerProtocol, Path::new("RUF072.py"))]
+    #[test_case(Rule::ComparisonWithRange, Path::new("RUF073.py"))]
+    #[test_case(Rule::IgnoredProcessReturnCode, Path::new("RUF074.py"))]
     #[test_case(Rule::RedirectedNOQA, Path::new("RUF101.py"))]
     fn rules(rule_code: Rule, path: &Path) -> Result<()> {
         let snapshot = format!("{}_{}", rule_code.noqa_code(), path.to_string_lossy());
@@ -67,6 +114,7 @@ mod tests {
 
     #[test_case(Path::new("RUF013_0.py"))]
     #[test_case(Path::new("RUF013_1.py"))]
+    #[test_case(Path::new("RUF013_4.py"))]
     fn implicit_optional_py39(path: &Path) -> Result<()> {
         let snapshot = format!(
             "PY39_{}_{}",
@@ -82,6 +130,119 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn multiple_with_items_needs_parens_py39() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("ruff/RUF034.py"),
+            &settings::LinterSettings::for_rule(Rule::MultipleWithItemsNeedsParens)
+                .with_target_version(PythonVersion::Py39),
+        )?;
+        assert_messages!(diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn inconsistent_optional_style_py38() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("ruff/RUF036.py"),
+            &settings::LinterSettings::for_rule(Rule::InconsistentOptionalStyle)
+                .with_target_version(PythonVersion::Py38),
+        )?;
+        assert_messages!(diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn inconsistent_optional_style_prefer_optional() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("ruff/RUF036.py"),
+            &settings::LinterSettings {
+                ruff: super::settings::Settings {
+                    optional_style: super::settings::OptionalStyle::Optional,
+                    ..Default::default()
+                },
+                ..settings::LinterSettings::for_rule(Rule::InconsistentOptionalStyle)
+            },
+        )?;
+        assert_messages!(diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn flag_idiomatic_conditional_imports() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("ruff/RUF068.py"),
+            &settings::LinterSettings {
+                ruff: super::settings::Settings {
+                    flag_idiomatic_conditional_imports: true,
+                    ..Default::default()
+                },
+                ..settings::LinterSettings::for_rule(Rule::ConflictingConditionalImport)
+            },
+        )?;
+        assert_messages!(diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn deeply_nested_fstring_py311() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("ruff/RUF048.py"),
+            &settings::LinterSettings::for_rule(Rule::DeeplyNestedFString)
+                .with_target_version(PythonVersion::Py311),
+        )?;
+        assert_messages!(diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn redundant_parentheses_on_return_multiline() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("ruff/RUF046.py"),
+            &settings::LinterSettings {
+                ruff: super::settings::Settings {
+                    flag_multiline_return_parens: true,
+                    ..Default::default()
+                },
+                ..settings::LinterSettings::for_rule(Rule::RedundantParenthesesOnReturn)
+            },
+        )?;
+        assert_messages!(diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn dunder_all_case_insensitive() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("ruff/RUF022.py"),
+            &settings::LinterSettings {
+                ruff: super::settings::Settings {
+                    dunder_all_case_insensitive: true,
+                    ..Default::default()
+                },
+                ..settings::LinterSettings::for_rule(Rule::UnsortedDunderAll)
+            },
+        )?;
+        assert_messages!(diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn flag_duplicate_call_decorators_off() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("ruff/RUF033.py"),
+            &settings::LinterSettings {
+                ruff: super::settings::Settings {
+                    flag_duplicate_call_decorators: false,
+                    ..Default::default()
+                },
+                ..settings::LinterSettings::for_rule(Rule::DuplicateDecorator)
+            },
+        )?;
+        assert_messages!(diagnostics);
+        Ok(())
+    }
+
     #[test]
     fn confusables() -> Result<()> {
         let diagnostics = test_path(
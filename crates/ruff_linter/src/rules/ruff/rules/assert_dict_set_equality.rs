@@ -0,0 +1,78 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, CmpOp, Expr};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `assert sorted(a) == sorted(b)` comparisons.
+///
+/// ## Why is this bad?
+/// Sorting both sides of an equality assertion signals that the order of the
+/// elements does not matter. When the elements are hashable and duplicates
+/// are irrelevant, comparing sets (`assert set(a) == set(b)`) states that
+/// intent directly and avoids the `O(n log n)` sorts; `collections.Counter`
+/// compares multisets when duplicates do matter.
+///
+/// This is guidance only: if duplicate counts are significant and the
+/// elements are unhashable, the `sorted` comparison is the right tool.
+///
+/// ## Example
+/// ```python
+/// assert sorted(result) == sorted(expected)
+/// ```
+///
+/// Use instead:
+/// ```python
+/// assert set(result) == set(expected)
+/// ```
+#[violation]
+pub struct AssertDictSetEquality;
+
+impl Violation for AssertDictSetEquality {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!(
+            "Order-insensitive `assert sorted(...) == sorted(...)`; consider comparing sets or `Counter`s"
+        )
+    }
+}
+
+/// RUF061
+pub(crate) fn assert_dict_set_equality(checker: &mut Checker, stmt: &ast::StmtAssert) {
+    let Expr::Compare(ast::ExprCompare {
+        left,
+        ops,
+        comparators,
+        range: _,
+    }) = stmt.test.as_ref()
+    else {
+        return;
+    };
+    let ([CmpOp::Eq], [comparator]) = (ops.as_ref(), comparators.as_ref()) else {
+        return;
+    };
+    if !is_sorted_call(left, checker) || !is_sorted_call(comparator, checker) {
+        return;
+    }
+    checker
+        .diagnostics
+        .push(Diagnostic::new(AssertDictSetEquality, stmt.test.range()));
+}
+
+/// Return `true` if the expression is a `sorted(...)` call without a `key`.
+fn is_sorted_call(expr: &Expr, checker: &Checker) -> bool {
+    let Expr::Call(ast::ExprCall {
+        func, arguments, ..
+    }) = expr
+    else {
+        return false;
+    };
+    // A `key` or `reverse` argument may make the order significant in ways a
+    // set comparison would not reproduce; stay conservative.
+    if !arguments.keywords.is_empty() {
+        return false;
+    }
+    checker.semantic().match_builtin_expr(func, "sorted")
+}
@@ -0,0 +1,51 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::Expr;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `assert` statements whose message is a tuple.
+///
+/// ## Why is this bad?
+/// In `assert condition, message`, a parenthesized message like `(a, b)` is a
+/// tuple, not a second condition. It's easy to write `assert x, ("msg",
+/// detail)` believing both elements will be checked or joined, when in fact
+/// the whole tuple is used verbatim as the assertion message. A non-empty
+/// tuple is also always truthy, so a misplaced comma can silently turn the
+/// intended condition into the message.
+///
+/// Use a single string message instead, formatting any details into it.
+///
+/// ## Example
+/// ```python
+/// assert x, ("x should be set", x)
+/// ```
+///
+/// Use instead:
+/// ```python
+/// assert x, f"x should be set, got {x}"
+/// ```
+///
+/// ## References
+/// - [Python documentation: The `assert` statement](https://docs.python.org/3/reference/simple_stmts.html#the-assert-statement)
+#[violation]
+pub struct AssertMessageSideEffect;
+
+impl Violation for AssertMessageSideEffect {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("Assertion message is a tuple; use a single formatted string instead")
+    }
+}
+
+/// RUF032
+pub(crate) fn assert_message_side_effect(checker: &mut Checker, msg: Option<&Expr>) {
+    let Some(Expr::Tuple(tuple)) = msg else {
+        return;
+    };
+    checker
+        .diagnostics
+        .push(Diagnostic::new(AssertMessageSideEffect, tuple.range()));
+}
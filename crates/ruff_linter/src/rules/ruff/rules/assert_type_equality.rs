@@ -0,0 +1,93 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, CmpOp, Expr};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `assert` statements comparing `type(x)` to a type with `==`.
+///
+/// ## Why is this bad?
+/// `assert type(x) == T` only passes for exactly `T`, rejecting subclasses,
+/// and is harder to read than `assert isinstance(x, T)`. Unless exact type
+/// identity is intended, prefer `isinstance`.
+///
+/// ## Example
+/// ```python
+/// assert type(x) == int
+/// ```
+///
+/// Use instead:
+/// ```python
+/// assert isinstance(x, int)
+/// ```
+///
+/// ## Fix safety
+/// This rule's fix is marked as unsafe, as `isinstance` also accepts
+/// instances of subclasses, so the rewritten assertion is strictly more
+/// permissive than the original.
+#[violation]
+pub struct AssertTypeEquality;
+
+impl Violation for AssertTypeEquality {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("`assert` on type equality; use `isinstance` instead (note: `isinstance` accepts subclasses)")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Replace with `isinstance`".to_string())
+    }
+}
+
+/// RUF051
+pub(crate) fn assert_type_equality(checker: &mut Checker, stmt: &ast::StmtAssert) {
+    let Expr::Compare(ast::ExprCompare {
+        left,
+        ops,
+        comparators,
+        range: _,
+    }) = stmt.test.as_ref()
+    else {
+        return;
+    };
+    let ([CmpOp::Eq], [comparator]) = (ops.as_ref(), comparators.as_ref()) else {
+        return;
+    };
+    // Require a plain class reference on the right; `type(x) == type(y)`
+    // compares type identity and has no `isinstance` equivalent.
+    if !matches!(comparator, Expr::Name(_) | Expr::Attribute(_)) {
+        return;
+    }
+    let Expr::Call(ast::ExprCall {
+        func, arguments, ..
+    }) = left.as_ref()
+    else {
+        return;
+    };
+    if !arguments.keywords.is_empty() {
+        return;
+    }
+    let [argument] = arguments.args.as_ref() else {
+        return;
+    };
+    if !checker.semantic().match_builtin_expr(func, "type") {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(AssertTypeEquality, stmt.test.range());
+    if checker.semantic().has_builtin_binding("isinstance") {
+        diagnostic.set_fix(Fix::unsafe_edit(Edit::range_replacement(
+            format!(
+                "isinstance({}, {})",
+                checker.locator().slice(argument),
+                checker.locator().slice(comparator)
+            ),
+            stmt.test.range(),
+        )));
+    }
+    checker.diagnostics.push(diagnostic);
+}
@@ -0,0 +1,111 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Expr};
+use ruff_python_semantic::analyze::typing::is_list;
+use ruff_python_semantic::Binding;
+use ruff_text_size::{Ranged, TextSize};
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for assignments of the result of an in-place list mutation, like
+/// `x = y.sort()`.
+///
+/// ## Why is this bad?
+/// In-place list methods (`sort`, `reverse`, `append`, etc.) mutate the list
+/// and return `None`; assigning their result binds `None`, which is almost
+/// always a bug. For `sort`, the builtin `sorted` returns the sorted list.
+///
+/// ## Example
+/// ```python
+/// x = y.sort()
+/// ```
+///
+/// Use instead:
+/// ```python
+/// x = sorted(y)
+/// ```
+///
+/// ## Fix safety
+/// A fix is only offered for `.sort()`, rewriting to `sorted(...)`. The fix
+/// is marked as unsafe, as the original (likely unintended) code also sorted
+/// the receiver in place, while `sorted` leaves it unchanged.
+#[violation]
+pub struct AssignmentFromSortInPlace {
+    method: String,
+}
+
+impl Violation for AssignmentFromSortInPlace {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let AssignmentFromSortInPlace { method } = self;
+        format!("`list.{method}` mutates in place and returns `None`")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some(format!("Replace with `sorted`"))
+    }
+}
+
+/// In-place `list` methods that always return `None`.
+const IN_PLACE_METHODS: &[&str] = &[
+    "append", "clear", "extend", "insert", "remove", "reverse", "sort",
+];
+
+/// RUF041
+pub(crate) fn assignment_from_sort_in_place(checker: &mut Checker, assign: &ast::StmtAssign) {
+    let Expr::Call(ast::ExprCall {
+        func, arguments, ..
+    }) = assign.value.as_ref()
+    else {
+        return;
+    };
+    let Expr::Attribute(ast::ExprAttribute { value, attr, .. }) = func.as_ref() else {
+        return;
+    };
+    if !IN_PLACE_METHODS.contains(&attr.as_str()) {
+        return;
+    }
+
+    // Restrict to receivers that can be inferred to be lists, to avoid
+    // flagging fluent APIs whose mutators return `self`.
+    let Expr::Name(ast::ExprName { id, .. }) = value.as_ref() else {
+        return;
+    };
+    let semantic = checker.semantic();
+    let bindings: Vec<&Binding> = semantic
+        .current_scope()
+        .get_all(id)
+        .map(|binding_id| semantic.binding(binding_id))
+        .collect();
+    let [binding] = bindings.as_slice() else {
+        return;
+    };
+    if !is_list(binding, semantic) {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(
+        AssignmentFromSortInPlace {
+            method: attr.to_string(),
+        },
+        assign.value.range(),
+    );
+
+    // `x = y.sort(...)` has a direct equivalent in `x = sorted(y, ...)`; the
+    // other mutators have no single-expression counterpart.
+    if attr == "sort" && semantic.has_builtin_binding("sorted") {
+        let prefix = if arguments.is_empty() { "" } else { ", " };
+        diagnostic.set_fix(Fix::unsafe_edits(
+            Edit::range_replacement("sorted".to_string(), func.range()),
+            [Edit::insertion(
+                format!("{id}{prefix}"),
+                arguments.start() + TextSize::from(1),
+            )],
+        ));
+    }
+
+    checker.diagnostics.push(diagnostic);
+}
@@ -0,0 +1,65 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Expr};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `await asyncio.gather(...)` calls that omit the
+/// `return_exceptions` keyword.
+///
+/// ## Why is this bad?
+/// By default, the first task to raise an exception causes `gather` to
+/// propagate it immediately, discarding the results of the remaining tasks.
+/// Passing `return_exceptions` explicitly — `True` to collect exceptions
+/// alongside results, or `False` to document that fail-fast behavior is
+/// intended — makes the choice visible to the reader.
+///
+/// ## Example
+/// ```python
+/// results = await asyncio.gather(fetch(a), fetch(b))
+/// ```
+///
+/// Use instead:
+/// ```python
+/// results = await asyncio.gather(fetch(a), fetch(b), return_exceptions=True)
+/// ```
+///
+/// No fix is offered, as the appropriate value is a semantic choice.
+///
+/// ## References
+/// - [Python documentation: `asyncio.gather`](https://docs.python.org/3/library/asyncio-task.html#asyncio.gather)
+#[violation]
+pub struct AsyncioGatherSwallowsExceptions;
+
+impl Violation for AsyncioGatherSwallowsExceptions {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("`asyncio.gather` without an explicit `return_exceptions` discards pending results on the first failure")
+    }
+}
+
+/// RUF055
+pub(crate) fn asyncio_gather_swallows_exceptions(checker: &mut Checker, call: &ast::ExprCall) {
+    if call.arguments.find_keyword("return_exceptions").is_some() {
+        return;
+    }
+    if !matches!(
+        checker.semantic().current_expression_parent(),
+        Some(Expr::Await(_))
+    ) {
+        return;
+    }
+    if !checker
+        .semantic()
+        .resolve_qualified_name(&call.func)
+        .is_some_and(|qualified_name| matches!(qualified_name.segments(), ["asyncio", "gather"]))
+    {
+        return;
+    }
+    checker.diagnostics.push(Diagnostic::new(
+        AsyncioGatherSwallowsExceptions,
+        call.range(),
+    ));
+}
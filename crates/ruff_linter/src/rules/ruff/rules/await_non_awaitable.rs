@@ -0,0 +1,120 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Expr, Stmt};
+use ruff_python_semantic::SemanticModel;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `await` expressions whose operand is provably not awaitable.
+///
+/// ## Why is this bad?
+/// Awaiting a non-awaitable value raises a `TypeError` at runtime. This
+/// usually indicates a forgotten call, or an `await` on a synchronous
+/// function's result.
+///
+/// To avoid false positives, only literals and calls to functions defined
+/// in the same file as plain (non-`async`, undecorated) functions with a
+/// non-awaitable return annotation are flagged.
+///
+/// ## Example
+/// ```python
+/// def compute() -> int: ...
+///
+///
+/// async def main():
+///     result = await compute()
+/// ```
+///
+/// Use instead:
+/// ```python
+/// def compute() -> int: ...
+///
+///
+/// async def main():
+///     result = compute()
+/// ```
+#[violation]
+pub struct AwaitNonAwaitable;
+
+impl Violation for AwaitNonAwaitable {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("`await` of a value that is not awaitable")
+    }
+}
+
+/// Return annotations that are known not to be awaitable.
+const NON_AWAITABLE_ANNOTATIONS: &[&str] = &[
+    "int",
+    "float",
+    "complex",
+    "bool",
+    "str",
+    "bytes",
+    "list",
+    "dict",
+    "set",
+    "frozenset",
+    "tuple",
+];
+
+/// RUF049
+pub(crate) fn await_non_awaitable(checker: &mut Checker, await_expr: &ast::ExprAwait) {
+    let non_awaitable = match await_expr.value.as_ref() {
+        Expr::NumberLiteral(_)
+        | Expr::BooleanLiteral(_)
+        | Expr::StringLiteral(_)
+        | Expr::BytesLiteral(_)
+        | Expr::NoneLiteral(_)
+        | Expr::EllipsisLiteral(_)
+        | Expr::FString(_)
+        | Expr::List(_)
+        | Expr::Dict(_)
+        | Expr::Set(_)
+        | Expr::Tuple(_) => true,
+        Expr::Call(ast::ExprCall { func, .. }) => is_sync_function_call(func, checker.semantic()),
+        _ => false,
+    };
+    if non_awaitable {
+        checker
+            .diagnostics
+            .push(Diagnostic::new(AwaitNonAwaitable, await_expr.range()));
+    }
+}
+
+/// Returns `true` if the callee is a plain function defined in the same file
+/// whose return annotation is known not to be awaitable.
+fn is_sync_function_call(func: &Expr, semantic: &SemanticModel) -> bool {
+    let Expr::Name(name) = func else {
+        return false;
+    };
+    let Some(binding_id) = semantic.resolve_name(name) else {
+        return false;
+    };
+    let binding = semantic.binding(binding_id);
+    if !binding.kind.is_function_definition() {
+        return false;
+    }
+    let Some(Stmt::FunctionDef(ast::StmtFunctionDef {
+        is_async,
+        decorator_list,
+        returns,
+        ..
+    })) = binding.statement(semantic)
+    else {
+        return false;
+    };
+    // A decorator could wrap the function in an awaitable, and an `async def`
+    // (or an unannotated return) could produce one.
+    if *is_async || !decorator_list.is_empty() {
+        return false;
+    }
+    let Some(returns) = returns.as_deref() else {
+        return false;
+    };
+    NON_AWAITABLE_ANNOTATIONS.iter().any(|annotation| {
+        semantic.match_builtin_expr(ruff_python_ast::helpers::map_subscript(returns), annotation)
+    }) || returns.is_none_literal_expr()
+}
@@ -0,0 +1,101 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, CmpOp, Expr};
+use ruff_python_semantic::analyze::typing;
+use ruff_python_semantic::{Binding, SemanticModel};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for comparisons mixing a `bytes` operand and a `str` operand.
+///
+/// ## Why is this bad?
+/// In Python 3, `bytes` and `str` never compare equal, so `==` always
+/// evaluates to `False` and `!=` to `True`; a `str` containment check on a
+/// `bytes` needle raises a `TypeError`. Such comparisons are almost always a
+/// bug: one of the operands is missing an encode or decode step.
+///
+/// ## Example
+/// ```python
+/// if data == "header":
+///     ...
+/// ```
+///
+/// Use instead:
+/// ```python
+/// if data == b"header":
+///     ...
+/// ```
+///
+/// No fix is offered, as the correct coercion depends on intent.
+#[violation]
+pub struct BytesStrComparison;
+
+impl Violation for BytesStrComparison {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("Comparison between `bytes` and `str` is always unequal")
+    }
+}
+
+/// RUF054
+pub(crate) fn bytes_str_comparison(checker: &mut Checker, compare: &ast::ExprCompare) {
+    for ((left, right), op) in std::iter::once(compare.left.as_ref())
+        .chain(compare.comparators.iter())
+        .zip(compare.comparators.iter())
+        .zip(compare.ops.iter())
+    {
+        if !matches!(op, CmpOp::Eq | CmpOp::NotEq | CmpOp::In | CmpOp::NotIn) {
+            continue;
+        }
+        let semantic = checker.semantic();
+        if (is_bytes(left, semantic) && is_str(right, semantic))
+            || (is_str(left, semantic) && is_bytes(right, semantic))
+        {
+            checker
+                .diagnostics
+                .push(Diagnostic::new(BytesStrComparison, compare.range()));
+            return;
+        }
+    }
+}
+
+/// Returns `true` if the expression is confidently bytes-typed: a bytes
+/// literal, or a name with a single bytes-typed binding.
+fn is_bytes(expr: &Expr, semantic: &SemanticModel) -> bool {
+    match expr {
+        Expr::BytesLiteral(_) => true,
+        Expr::Name(name) => single_binding(name, semantic)
+            .is_some_and(|binding| typing::is_bytes(binding, semantic)),
+        _ => false,
+    }
+}
+
+/// Returns `true` if the expression is confidently str-typed: a string
+/// literal, or a name with a single str-typed binding.
+fn is_str(expr: &Expr, semantic: &SemanticModel) -> bool {
+    match expr {
+        Expr::StringLiteral(_) | Expr::FString(_) => true,
+        Expr::Name(name) => {
+            single_binding(name, semantic).is_some_and(|binding| typing::is_str(binding, semantic))
+        }
+        _ => false,
+    }
+}
+
+/// Return the binding for the name, if it is the only one in the current scope.
+fn single_binding<'a>(
+    name: &ast::ExprName,
+    semantic: &'a SemanticModel,
+) -> Option<&'a Binding<'a>> {
+    let bindings: Vec<&Binding> = semantic
+        .current_scope()
+        .get_all(name.id.as_str())
+        .map(|id| semantic.binding(id))
+        .collect();
+    let [binding] = bindings.as_slice() else {
+        return None;
+    };
+    Some(binding)
+}
@@ -0,0 +1,109 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, CmpOp, Expr, Number};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for comparisons with a `range` object that always evaluate to the
+/// same result, like `[0, 1, 2] == range(3)` or `0.5 in range(10)`.
+///
+/// ## Why is this bad?
+/// A `range` object only compares equal to another `range`, so comparing it
+/// to a list, tuple, set, or dict literal with `==` is always `False` (and
+/// `!=` is always `True`). Likewise, a `range` only contains integers, so a
+/// float literal is never a member. Such comparisons usually indicate that
+/// the author meant to convert one of the operands, as in
+/// `values == list(range(3))`.
+///
+/// ## Example
+/// ```python
+/// if [0, 1, 2] == range(3):
+///     ...
+/// ```
+///
+/// Use instead:
+/// ```python
+/// if [0, 1, 2] == list(range(3)):
+///     ...
+/// ```
+///
+/// ## References
+/// - [Python documentation: Ranges](https://docs.python.org/3/library/stdtypes.html#ranges)
+#[violation]
+pub struct ComparisonWithRange {
+    result: bool,
+}
+
+impl Violation for ComparisonWithRange {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let ComparisonWithRange { result } = self;
+        format!(
+            "Comparison with `range` always evaluates to `{}`",
+            if *result { "True" } else { "False" }
+        )
+    }
+}
+
+/// RUF073
+pub(crate) fn comparison_with_range(checker: &mut Checker, compare: &ast::ExprCompare) {
+    for (index, (op, right)) in compare.ops.iter().zip(&compare.comparators).enumerate() {
+        let left = if index == 0 {
+            compare.left.as_ref()
+        } else {
+            &compare.comparators[index - 1]
+        };
+
+        let result = match op {
+            // Ex) `[0, 1, 2] == range(3)` is always `False`.
+            CmpOp::Eq | CmpOp::NotEq => {
+                let mismatched = (is_range_call(left, checker) && is_collection_literal(right))
+                    || (is_collection_literal(left) && is_range_call(right, checker));
+                if !mismatched {
+                    continue;
+                }
+                matches!(op, CmpOp::NotEq)
+            }
+            // Ex) `0.5 in range(10)` is always `False`.
+            CmpOp::In | CmpOp::NotIn => {
+                if !(is_float_literal(left) && is_range_call(right, checker)) {
+                    continue;
+                }
+                matches!(op, CmpOp::NotIn)
+            }
+            _ => continue,
+        };
+
+        checker.diagnostics.push(Diagnostic::new(
+            ComparisonWithRange { result },
+            compare.range(),
+        ));
+    }
+}
+
+/// Return `true` if the expression is a call to the `range` builtin.
+fn is_range_call(expr: &Expr, checker: &Checker) -> bool {
+    expr.as_call_expr()
+        .is_some_and(|call| checker.semantic().match_builtin_expr(&call.func, "range"))
+}
+
+/// Return `true` if the expression is a list, tuple, set, or dict literal.
+fn is_collection_literal(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::List(_) | Expr::Tuple(_) | Expr::Set(_) | Expr::Dict(_)
+    )
+}
+
+/// Return `true` if the expression is a float literal.
+fn is_float_literal(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::NumberLiteral(ast::ExprNumberLiteral {
+            value: Number::Float(_),
+            ..
+        })
+    )
+}
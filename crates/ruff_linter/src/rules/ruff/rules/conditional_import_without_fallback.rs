@@ -0,0 +1,133 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, ExceptHandler, Expr, Stmt};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `try`/`except ImportError` blocks whose handler does not bind
+/// the imported name.
+///
+/// ## Why is this bad?
+/// A conditional import like `try: import fast_json as json` relies on the
+/// `except ImportError` handler to provide a fallback binding. If the handler
+/// merely passes, the name is left undefined when the import fails, and the
+/// first use raises a `NameError` far from the cause.
+///
+/// ## Example
+/// ```python
+/// try:
+///     import fast_json as json
+/// except ImportError:
+///     pass
+/// ```
+///
+/// Use instead:
+/// ```python
+/// try:
+///     import fast_json as json
+/// except ImportError:
+///     import json
+/// ```
+///
+/// No fix is offered, as the appropriate fallback is specific to each import.
+#[violation]
+pub struct ConditionalImportWithoutFallback {
+    name: String,
+}
+
+impl Violation for ConditionalImportWithoutFallback {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let ConditionalImportWithoutFallback { name } = self;
+        format!("`except ImportError` leaves `{name}` undefined when the import fails")
+    }
+}
+
+/// RUF058
+pub(crate) fn conditional_import_without_fallback(checker: &mut Checker, try_stmt: &ast::StmtTry) {
+    // Only consider `try` bodies that are purely imports.
+    if try_stmt.body.is_empty()
+        || !try_stmt
+            .body
+            .iter()
+            .all(|stmt| matches!(stmt, Stmt::Import(_) | Stmt::ImportFrom(_)))
+    {
+        return;
+    }
+    let imported: Vec<&str> = try_stmt.body.iter().flat_map(bound_names).collect();
+
+    for handler in &try_stmt.handlers {
+        let ExceptHandler::ExceptHandler(handler) = handler;
+        if !is_import_error_handler(handler, checker) {
+            continue;
+        }
+        // A handler that raises (or re-raises) never falls through to the
+        // undefined name.
+        if handler
+            .body
+            .iter()
+            .any(|stmt| matches!(stmt, Stmt::Raise(_)))
+        {
+            continue;
+        }
+        let fallbacks: Vec<&str> = handler.body.iter().flat_map(bound_names).collect();
+        if let Some(missing) = imported.iter().find(|name| !fallbacks.contains(name)) {
+            checker.diagnostics.push(Diagnostic::new(
+                ConditionalImportWithoutFallback {
+                    name: (*missing).to_string(),
+                },
+                handler.range(),
+            ));
+        }
+    }
+}
+
+/// Return `true` if the handler catches `ImportError` or `ModuleNotFoundError`.
+fn is_import_error_handler(handler: &ast::ExceptHandlerExceptHandler, checker: &Checker) -> bool {
+    let Some(type_) = handler.type_.as_deref() else {
+        return false;
+    };
+    let matches_import_error = |expr: &Expr| {
+        checker
+            .semantic()
+            .resolve_builtin_symbol(expr)
+            .is_some_and(|builtin| matches!(builtin, "ImportError" | "ModuleNotFoundError"))
+    };
+    match type_ {
+        Expr::Tuple(ast::ExprTuple { elts, .. }) => elts.iter().any(matches_import_error),
+        type_ => matches_import_error(type_),
+    }
+}
+
+/// Return the names bound by the statement, considering imports and simple
+/// assignments.
+fn bound_names(stmt: &Stmt) -> Vec<&str> {
+    match stmt {
+        Stmt::Import(ast::StmtImport { names, .. }) => names
+            .iter()
+            .map(|alias| match &alias.asname {
+                Some(asname) => asname.as_str(),
+                None => alias.name.split('.').next().unwrap_or(alias.name.as_str()),
+            })
+            .collect(),
+        Stmt::ImportFrom(ast::StmtImportFrom { names, .. }) => names
+            .iter()
+            .map(|alias| {
+                alias
+                    .asname
+                    .as_ref()
+                    .map_or(alias.name.as_str(), ruff_python_ast::Identifier::as_str)
+            })
+            .collect(),
+        Stmt::Assign(ast::StmtAssign { targets, .. }) => targets
+            .iter()
+            .filter_map(|target| match target {
+                Expr::Name(name) => Some(name.id.as_str()),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
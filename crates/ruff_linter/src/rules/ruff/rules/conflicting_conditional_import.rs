@@ -0,0 +1,117 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Alias, ExceptHandler, Expr, Stmt};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `try`/`except ImportError` fallbacks that bind the same name
+/// to an unrelated module.
+///
+/// ## Why is this bad?
+/// The conditional-import idiom rebinds one name to interchangeable modules,
+/// as in `try: import ujson as json except ImportError: import json`. When
+/// neither branch imports the module the name actually refers to, readers
+/// can no longer tell which API the name provides, and the two modules are
+/// unlikely to be drop-in replacements for one another.
+///
+/// ## Example
+/// ```python
+/// try:
+///     import fast_parser as parser
+/// except ImportError:
+///     import tolerant_reader as parser
+/// ```
+///
+/// ## Options
+/// - `lint.ruff.flag-idiomatic-conditional-imports`
+#[violation]
+pub struct ConflictingConditionalImport {
+    name: String,
+}
+
+impl Violation for ConflictingConditionalImport {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let ConflictingConditionalImport { name } = self;
+        format!("Fallback import binds `{name}` to an unrelated module")
+    }
+}
+
+/// RUF068
+pub(crate) fn conflicting_conditional_import(checker: &mut Checker, try_stmt: &ast::StmtTry) {
+    // (name, module) pairs bound by `import` statements in the `try` body.
+    let imported: Vec<(&str, &str)> = try_stmt
+        .body
+        .iter()
+        .flat_map(import_bindings)
+        .map(|alias| (bound_name(alias), alias.name.as_str()))
+        .collect();
+    if imported.is_empty() {
+        return;
+    }
+
+    for handler in &try_stmt.handlers {
+        let ExceptHandler::ExceptHandler(handler) = handler;
+        if !is_import_error_handler(handler, checker) {
+            continue;
+        }
+        for alias in handler.body.iter().flat_map(import_bindings) {
+            let name = bound_name(alias);
+            let Some((_, module)) = imported.iter().find(|(bound, _)| *bound == name) else {
+                continue;
+            };
+            if *module == alias.name.as_str() {
+                continue;
+            }
+            // `import ujson as json` falling back to `import json` (in either
+            // order) is the idiomatic accelerator pattern: one of the modules
+            // is the one the name refers to.
+            if !checker.settings.ruff.flag_idiomatic_conditional_imports
+                && (*module == name || alias.name.as_str() == name)
+            {
+                continue;
+            }
+            checker.diagnostics.push(Diagnostic::new(
+                ConflictingConditionalImport {
+                    name: name.to_string(),
+                },
+                alias.range(),
+            ));
+        }
+    }
+}
+
+/// Return the aliases bound by the statement, if it is a plain `import`.
+fn import_bindings(stmt: &Stmt) -> &[Alias] {
+    match stmt {
+        Stmt::Import(ast::StmtImport { names, .. }) => names,
+        _ => &[],
+    }
+}
+
+/// Return the name an `import` alias binds.
+fn bound_name(alias: &Alias) -> &str {
+    match &alias.asname {
+        Some(asname) => asname.as_str(),
+        None => alias.name.split('.').next().unwrap_or(alias.name.as_str()),
+    }
+}
+
+/// Return `true` if the handler catches `ImportError` or `ModuleNotFoundError`.
+fn is_import_error_handler(handler: &ast::ExceptHandlerExceptHandler, checker: &Checker) -> bool {
+    let Some(type_) = handler.type_.as_deref() else {
+        return false;
+    };
+    let matches_import_error = |expr: &Expr| {
+        checker
+            .semantic()
+            .resolve_builtin_symbol(expr)
+            .is_some_and(|builtin| matches!(builtin, "ImportError" | "ModuleNotFoundError"))
+    };
+    match type_ {
+        Expr::Tuple(ast::ExprTuple { elts, .. }) => elts.iter().any(matches_import_error),
+        type_ => matches_import_error(type_),
+    }
+}
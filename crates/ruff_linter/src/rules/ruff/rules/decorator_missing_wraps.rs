@@ -0,0 +1,151 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::helpers::map_callable;
+use ruff_python_ast::whitespace::indentation;
+use ruff_python_ast::{self as ast, Expr, Stmt};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+use crate::importer::ImportRequest;
+
+/// ## What it does
+/// Checks for decorator functions whose wrapper is not decorated with
+/// `functools.wraps`.
+///
+/// ## Why is this bad?
+/// A decorator that returns a plain wrapper function replaces the decorated
+/// function's metadata — its `__name__`, `__doc__`, `__module__`, and
+/// signature — with the wrapper's. This breaks introspection, documentation
+/// tools, and any code that dispatches on function identity. Applying
+/// `functools.wraps` to the wrapper copies the metadata over.
+///
+/// ## Example
+/// ```python
+/// def log_calls(func):
+///     def wrapper(*args, **kwargs):
+///         print(f"Calling {func.__name__}")
+///         return func(*args, **kwargs)
+///
+///     return wrapper
+/// ```
+///
+/// Use instead:
+/// ```python
+/// import functools
+///
+///
+/// def log_calls(func):
+///     @functools.wraps(func)
+///     def wrapper(*args, **kwargs):
+///         print(f"Calling {func.__name__}")
+///         return func(*args, **kwargs)
+///
+///     return wrapper
+/// ```
+///
+/// ## Fix safety
+/// The fix inserts `@functools.wraps(...)` above the wrapper function and
+/// imports `functools` if necessary. It is marked as unsafe, as copying the
+/// wrapped function's metadata onto the wrapper changes runtime behavior for
+/// code that inspects the wrapper's attributes.
+///
+/// ## References
+/// - [Python documentation: `functools.wraps`](https://docs.python.org/3/library/functools.html#functools.wraps)
+#[violation]
+pub struct DecoratorMissingWraps {
+    wrapper: String,
+    param: String,
+}
+
+impl Violation for DecoratorMissingWraps {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let DecoratorMissingWraps { wrapper, param } = self;
+        format!("Wrapper function `{wrapper}` is missing `@functools.wraps({param})`")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let DecoratorMissingWraps { param, .. } = self;
+        Some(format!("Add `@functools.wraps({param})`"))
+    }
+}
+
+/// RUF069
+pub(crate) fn decorator_missing_wraps(checker: &mut Checker, function_def: &ast::StmtFunctionDef) {
+    // A decorator takes a single (positional) parameter: the function to wrap.
+    let parameters = &function_def.parameters;
+    if !parameters.posonlyargs.is_empty()
+        || !parameters.kwonlyargs.is_empty()
+        || parameters.vararg.is_some()
+        || parameters.kwarg.is_some()
+    {
+        return;
+    }
+    let [parameter] = parameters.args.as_slice() else {
+        return;
+    };
+    let param = parameter.parameter.name.as_str();
+
+    // Collect the names returned from the function body.
+    let returned_names: Vec<&str> = function_def
+        .body
+        .iter()
+        .filter_map(|stmt| {
+            let Stmt::Return(ast::StmtReturn {
+                value: Some(value), ..
+            }) = stmt
+            else {
+                return None;
+            };
+            let Expr::Name(ast::ExprName { id, .. }) = value.as_ref() else {
+                return None;
+            };
+            Some(id.as_str())
+        })
+        .collect();
+    if returned_names.is_empty() {
+        return;
+    }
+
+    for stmt in &function_def.body {
+        let Stmt::FunctionDef(wrapper) = stmt else {
+            continue;
+        };
+        if !returned_names.contains(&wrapper.name.as_str()) {
+            continue;
+        }
+        if wrapper.decorator_list.iter().any(|decorator| {
+            checker
+                .semantic()
+                .resolve_qualified_name(map_callable(&decorator.expression))
+                .is_some_and(|qualified_name| {
+                    matches!(qualified_name.segments(), ["functools", "wraps"])
+                })
+        }) {
+            continue;
+        }
+
+        let mut diagnostic = Diagnostic::new(
+            DecoratorMissingWraps {
+                wrapper: wrapper.name.to_string(),
+                param: param.to_string(),
+            },
+            wrapper.name.range(),
+        );
+        if let Some(indent) = indentation(checker.locator(), stmt) {
+            let indent = indent.to_string();
+            diagnostic.try_set_fix(|| {
+                let (import_edit, binding) = checker.importer().get_or_import_symbol(
+                    &ImportRequest::import("functools", "wraps"),
+                    stmt.start(),
+                    checker.semantic(),
+                )?;
+                let edit = Edit::insertion(format!("@{binding}({param})\n{indent}"), stmt.start());
+                Ok(Fix::unsafe_edits(edit, [import_edit]))
+            });
+        }
+        checker.diagnostics.push(diagnostic);
+    }
+}
@@ -0,0 +1,95 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::visitor::{walk_expr, Visitor};
+use ruff_python_ast::Expr;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+use crate::settings::types::PythonVersion;
+
+/// ## What it does
+/// Checks for f-strings whose replacement fields contain further f-strings,
+/// nested beyond a configurable depth.
+///
+/// ## Why is this bad?
+/// Deeply nested f-strings are hard to read, and prior to Python 3.12,
+/// reusing the same quote character inside a replacement field was a syntax
+/// error, making nested f-strings fragile under reformatting. When targeting
+/// a version below 3.12, any nesting at all is flagged; otherwise, only
+/// nesting beyond [`lint.ruff.max-fstring-nesting-depth`] is flagged.
+///
+/// ## Example
+/// ```python
+/// f"{f'{f"{x}"}'}"
+/// ```
+///
+/// Use instead:
+/// ```python
+/// inner = f"{x}"
+/// f"{inner}"
+/// ```
+///
+/// ## Options
+/// - `lint.ruff.max-fstring-nesting-depth`
+#[violation]
+pub struct DeeplyNestedFString {
+    depth: u32,
+}
+
+impl Violation for DeeplyNestedFString {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let DeeplyNestedFString { depth } = self;
+        format!("f-string nested {depth} levels deep")
+    }
+}
+
+/// RUF048
+pub(crate) fn deeply_nested_fstring(checker: &mut Checker, expr: &Expr) {
+    // Only report at the outermost f-string.
+    if checker
+        .semantic()
+        .current_expressions()
+        .skip(1)
+        .any(Expr::is_f_string_expr)
+    {
+        return;
+    }
+
+    let max_depth = if checker.settings.target_version < PythonVersion::Py312 {
+        1
+    } else {
+        checker.settings.ruff.max_fstring_nesting_depth
+    };
+
+    let mut visitor = NestingVisitor::default();
+    visitor.visit_expr(expr);
+    if visitor.max_depth > max_depth {
+        checker.diagnostics.push(Diagnostic::new(
+            DeeplyNestedFString {
+                depth: visitor.max_depth,
+            },
+            expr.range(),
+        ));
+    }
+}
+
+/// Visitor tracking the deepest f-string nesting within an expression.
+#[derive(Default)]
+struct NestingVisitor {
+    depth: u32,
+    max_depth: u32,
+}
+
+impl<'a> Visitor<'a> for NestingVisitor {
+    fn visit_expr(&mut self, expr: &'a Expr) {
+        if expr.is_f_string_expr() {
+            self.depth += 1;
+            self.max_depth = self.max_depth.max(self.depth);
+            walk_expr(self, expr);
+            self.depth -= 1;
+        } else {
+            walk_expr(self, expr);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Expr, Stmt};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `del` statements that delete the enclosing loop's variable.
+///
+/// ## Why is this bad?
+/// Deleting the loop variable inside the loop body removes the binding
+/// mid-iteration: any later use in the same iteration raises a `NameError`,
+/// and the deletion is undone as soon as the next iteration rebinds the
+/// variable, which makes the intent hard to follow.
+///
+/// ## Example
+/// ```python
+/// for item in items:
+///     process(item)
+///     del item
+/// ```
+///
+/// Use instead:
+/// ```python
+/// for item in items:
+///     process(item)
+/// ```
+#[violation]
+pub struct DeleteLoopVariable {
+    name: String,
+}
+
+impl Violation for DeleteLoopVariable {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let DeleteLoopVariable { name } = self;
+        format!("`del` of loop variable `{name}` inside the loop")
+    }
+}
+
+/// RUF066
+pub(crate) fn delete_loop_variable(checker: &mut Checker, delete: &ast::StmtDelete) {
+    for target in &delete.targets {
+        let Expr::Name(name) = target else {
+            continue;
+        };
+        if checker
+            .semantic()
+            .current_statements()
+            .any(|stmt| matches!(stmt, Stmt::For(for_stmt) if targets_name(&for_stmt.target, name.id.as_str())))
+        {
+            checker.diagnostics.push(Diagnostic::new(
+                DeleteLoopVariable {
+                    name: name.id.to_string(),
+                },
+                delete.range(),
+            ));
+            return;
+        }
+    }
+}
+
+/// Return `true` if the loop target binds the given name, including within
+/// tuple unpacking.
+fn targets_name(target: &Expr, name: &str) -> bool {
+    match target {
+        Expr::Name(target) => target.id == name,
+        Expr::Tuple(ast::ExprTuple { elts, .. }) | Expr::List(ast::ExprList { elts, .. }) => {
+            elts.iter().any(|elt| targets_name(elt, name))
+        }
+        Expr::Starred(ast::ExprStarred { value, .. }) => targets_name(value, name),
+        _ => false,
+    }
+}
@@ -0,0 +1,101 @@
+use rustc_hash::FxHashSet;
+
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::comparable::ComparableExpr;
+use ruff_python_ast::Decorator;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for functions and classes with the same decorator applied more
+/// than once.
+///
+/// ## Why is this bad?
+/// Applying an identical decorator twice is at best redundant, and at worst
+/// a typo for a different decorator. For decorators with registration
+/// semantics (like `@app.route("/")`), the duplicate registration may also
+/// be an error in its own right.
+///
+/// By default, decorators that are calls (like `@app.route("/")`) are
+/// included; set [`lint.ruff.flag-duplicate-call-decorators`] to `false`
+/// to restrict the rule to bare decorators, for frameworks in which repeated
+/// registration calls are intentional.
+///
+/// ## Example
+/// ```python
+/// @staticmethod
+/// @staticmethod
+/// def f(): ...
+/// ```
+///
+/// Use instead:
+/// ```python
+/// @staticmethod
+/// def f(): ...
+/// ```
+///
+/// ## Fix safety
+/// This rule's fix is marked as unsafe, as removing a decorator may change
+/// runtime behavior if the decorator has side effects.
+///
+/// ## Options
+/// - `lint.ruff.flag-duplicate-call-decorators`
+#[violation]
+pub struct DuplicateDecorator {
+    decorator: String,
+}
+
+impl Violation for DuplicateDecorator {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let DuplicateDecorator { decorator } = self;
+        format!("Duplicate decorator `{decorator}`")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some(format!("Remove duplicate decorator"))
+    }
+}
+
+/// RUF033
+pub(crate) fn duplicate_decorator(checker: &mut Checker, decorator_list: &[Decorator]) {
+    if decorator_list.len() < 2 {
+        return;
+    }
+
+    let mut seen: FxHashSet<ComparableExpr> = FxHashSet::default();
+    for decorator in decorator_list {
+        if decorator.expression.is_call_expr()
+            && !checker.settings.ruff.flag_duplicate_call_decorators
+        {
+            continue;
+        }
+
+        let comparable: ComparableExpr = (&decorator.expression).into();
+        if seen.insert(comparable) {
+            continue;
+        }
+
+        let mut diagnostic = Diagnostic::new(
+            DuplicateDecorator {
+                decorator: checker.generator().expr(&decorator.expression),
+            },
+            decorator.range(),
+        );
+
+        // Only delete the duplicate when it occupies its own line(s), to
+        // avoid mangling code like `@a @b` hidden behind a continuation.
+        let full_range = checker.locator().full_lines_range(decorator.range());
+        if checker.locator().slice(full_range).trim().len()
+            == checker.locator().slice(decorator).len()
+        {
+            diagnostic.set_fix(Fix::unsafe_edit(Edit::range_deletion(full_range)));
+        }
+
+        checker.diagnostics.push(diagnostic);
+    }
+}
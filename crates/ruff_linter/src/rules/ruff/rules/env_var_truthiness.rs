@@ -0,0 +1,123 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Expr, Stmt, UnaryOp};
+use ruff_python_semantic::{Modules, SemanticModel};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `os.getenv` and `os.environ.get` calls without a default that
+/// are used directly as a condition.
+///
+/// ## Why is this bad?
+/// An unset environment variable yields `None`, which is falsy; but a
+/// variable that is set to the empty string is falsy too, while a variable
+/// set to `"0"`, `"false"`, or `"no"` is truthy. Using the raw lookup as a
+/// condition thus answers "is the variable set to a non-empty value?" rather
+/// than "is the flag enabled?", which is a common source of configuration
+/// bugs.
+///
+/// Instead, compare the value against an explicit sentinel, or parse it with
+/// a helper that understands boolean-like strings.
+///
+/// ## Example
+/// ```python
+/// import os
+///
+/// if os.getenv("DEBUG"):
+///     enable_debugging()
+/// ```
+///
+/// Use instead:
+/// ```python
+/// import os
+///
+/// if os.getenv("DEBUG") == "1":
+///     enable_debugging()
+/// ```
+///
+/// ## References
+/// - [Python documentation: `os.getenv`](https://docs.python.org/3/library/os.html#os.getenv)
+#[violation]
+pub struct EnvVarTruthiness;
+
+impl Violation for EnvVarTruthiness {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!(
+            "Truthiness of an environment variable only checks that it is set and non-empty; compare against an explicit value instead"
+        )
+    }
+}
+
+/// Returns `true` if the call is used as a boolean test: the condition of an
+/// `if`/`elif`/`while`/`assert` or conditional expression, or an operand of a
+/// boolean operator or `not`.
+fn is_boolean_test(call: &ast::ExprCall, semantic: &SemanticModel) -> bool {
+    let range = call.range();
+    match semantic.current_expression_parent() {
+        Some(Expr::BoolOp(_)) => true,
+        Some(Expr::UnaryOp(ast::ExprUnaryOp {
+            op: UnaryOp::Not, ..
+        })) => true,
+        Some(Expr::If(ast::ExprIf { test, .. })) => test.range() == range,
+        Some(_) => false,
+        None => match semantic.current_statement() {
+            Stmt::If(ast::StmtIf {
+                test,
+                elif_else_clauses,
+                ..
+            }) => {
+                test.range() == range
+                    || elif_else_clauses.iter().any(|clause| {
+                        clause
+                            .test
+                            .as_ref()
+                            .is_some_and(|test| test.range() == range)
+                    })
+            }
+            Stmt::While(ast::StmtWhile { test, .. }) => test.range() == range,
+            Stmt::Assert(ast::StmtAssert { test, .. }) => test.range() == range,
+            _ => false,
+        },
+    }
+}
+
+/// RUF031
+pub(crate) fn env_var_truthiness(checker: &mut Checker, call: &ast::ExprCall) {
+    if !checker.semantic().seen_module(Modules::OS) {
+        return;
+    }
+
+    if !is_boolean_test(call, checker.semantic()) {
+        return;
+    }
+
+    if !checker
+        .semantic()
+        .resolve_qualified_name(&call.func)
+        .is_some_and(|qualified_name| {
+            matches!(
+                qualified_name.segments(),
+                ["os", "getenv"] | ["os", "environ", "get"]
+            )
+        })
+    {
+        return;
+    }
+
+    // A non-`None` default makes the truthiness explicit, as in
+    // `os.getenv("DEBUG", "0")`.
+    if call
+        .arguments
+        .find_argument("default", 1)
+        .is_some_and(|default| !default.is_none_literal_expr())
+    {
+        return;
+    }
+
+    checker
+        .diagnostics
+        .push(Diagnostic::new(EnvVarTruthiness, call.range()));
+}
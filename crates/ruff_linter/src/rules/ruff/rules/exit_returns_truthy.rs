@@ -0,0 +1,78 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::helpers::{ReturnStatementVisitor, Truthiness};
+use ruff_python_ast::visitor::Visitor;
+use ruff_python_ast::{self as ast};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `__exit__` methods that return a truthy constant.
+///
+/// ## Why is this bad?
+/// Returning a truthy value from `__exit__` tells the interpreter to suppress
+/// whatever exception was raised inside the `with` block. Returning a
+/// constant like `True` suppresses _every_ exception unconditionally, which
+/// silently hides bugs. If suppression is intended, it should be based on the
+/// exception arguments; otherwise, return `None` (or nothing).
+///
+/// A computed return value is not flagged, since conditionally suppressing
+/// specific exceptions is the documented use of the return value.
+///
+/// ## Example
+/// ```python
+/// class Guard:
+///     def __exit__(self, exc_type, exc, tb):
+///         self.close()
+///         return True
+/// ```
+///
+/// Use instead:
+/// ```python
+/// class Guard:
+///     def __exit__(self, exc_type, exc, tb):
+///         self.close()
+/// ```
+///
+/// ## References
+/// - [Python documentation: `object.__exit__`](https://docs.python.org/3/reference/datamodel.html#object.__exit__)
+#[violation]
+pub struct ExitReturnsTruthy;
+
+impl Violation for ExitReturnsTruthy {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("`__exit__` returns a truthy constant, suppressing all exceptions")
+    }
+}
+
+/// RUF067
+pub(crate) fn exit_returns_truthy(checker: &mut Checker, function_def: &ast::StmtFunctionDef) {
+    if !matches!(function_def.name.as_str(), "__exit__" | "__aexit__") {
+        return;
+    }
+
+    if !checker.semantic().current_scope().kind.is_class() {
+        return;
+    }
+
+    let returns = {
+        let mut visitor = ReturnStatementVisitor::default();
+        visitor.visit_body(&function_def.body);
+        visitor.returns
+    };
+
+    for stmt in returns {
+        let Some(value) = stmt.value.as_deref() else {
+            continue;
+        };
+        let truthiness =
+            Truthiness::from_expr(value, |id| checker.semantic().has_builtin_binding(id));
+        if truthiness.into_bool() == Some(true) {
+            checker
+                .diagnostics
+                .push(Diagnostic::new(ExitReturnsTruthy, stmt.range()));
+        }
+    }
+}
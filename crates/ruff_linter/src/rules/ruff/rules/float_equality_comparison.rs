@@ -0,0 +1,92 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, CmpOp, Expr, Number, UnaryOp};
+use ruff_python_semantic::analyze::typing;
+use ruff_python_semantic::{Binding, SemanticModel};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `==` and `!=` comparisons in which at least one operand is a
+/// float.
+///
+/// ## Why is this bad?
+/// Due to the imprecision of floating-point representation, values that are
+/// mathematically equal may compare unequal (e.g., `0.1 + 0.2 == 0.3` is
+/// `False`). Comparing floats with a tolerance, as with `math.isclose`, is
+/// more reliable.
+///
+/// ## Example
+/// ```python
+/// if x == 0.1:
+///     ...
+/// ```
+///
+/// Use instead:
+/// ```python
+/// import math
+///
+/// if math.isclose(x, 0.1):
+///     ...
+/// ```
+///
+/// No fix is offered, as an appropriate tolerance depends on the use case.
+///
+/// ## References
+/// - [Python documentation: `math.isclose`](https://docs.python.org/3/library/math.html#math.isclose)
+#[violation]
+pub struct FloatEqualityComparison;
+
+impl Violation for FloatEqualityComparison {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("Equality comparison with a float is unreliable; use `math.isclose` instead")
+    }
+}
+
+/// RUF044
+pub(crate) fn float_equality_comparison(checker: &mut Checker, compare: &ast::ExprCompare) {
+    for ((left, right), op) in std::iter::once(compare.left.as_ref())
+        .chain(compare.comparators.iter())
+        .zip(compare.comparators.iter())
+        .zip(compare.ops.iter())
+    {
+        if !matches!(op, CmpOp::Eq | CmpOp::NotEq) {
+            continue;
+        }
+        if is_float(left, checker.semantic()) || is_float(right, checker.semantic()) {
+            checker
+                .diagnostics
+                .push(Diagnostic::new(FloatEqualityComparison, compare.range()));
+            return;
+        }
+    }
+}
+
+/// Returns `true` if the expression is confidently float-typed: a float
+/// literal, or a name with a single float-typed binding.
+fn is_float(expr: &Expr, semantic: &SemanticModel) -> bool {
+    match expr {
+        Expr::NumberLiteral(ast::ExprNumberLiteral { value, .. }) => {
+            matches!(value, Number::Float(_))
+        }
+        Expr::UnaryOp(ast::ExprUnaryOp {
+            op: UnaryOp::UAdd | UnaryOp::USub,
+            operand,
+            ..
+        }) => is_float(operand, semantic),
+        Expr::Name(name) => {
+            let bindings: Vec<&Binding> = semantic
+                .current_scope()
+                .get_all(name.id.as_str())
+                .map(|id| semantic.binding(id))
+                .collect();
+            let [binding] = bindings.as_slice() else {
+                return false;
+            };
+            typing::is_float(binding, semantic)
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,84 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, FStringElement};
+use ruff_text_size::{Ranged, TextRange, TextSize};
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for f-strings that use the `=` debug specifier, as in `f"{x=}"`.
+///
+/// ## Why is this bad?
+/// The self-documenting `{x=}` form is a debugging aid: it prints the
+/// expression source alongside its value. Such output is rarely intended to
+/// ship, and is often left over from a debugging session.
+///
+/// In the rare case where the `name=value` output is intentional, this rule
+/// can be disabled for the line in question.
+///
+/// ## Example
+/// ```python
+/// print(f"{value=}")
+/// ```
+///
+/// Use instead:
+/// ```python
+/// print(f"{value}")
+/// ```
+///
+/// ## Fix safety
+/// The fix removes the `=` specifier, which removes the `value=` prefix from
+/// the formatted output (and, absent an explicit conversion, switches from
+/// `repr` to `str` formatting). As such, the fix is always marked as unsafe.
+#[violation]
+pub struct FStringDebugSpecifier;
+
+impl Violation for FStringDebugSpecifier {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("f-string contains `=` debug specifier")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some(format!("Remove `=` specifier"))
+    }
+}
+
+/// RUF037
+pub(crate) fn fstring_debug_specifier(checker: &mut Checker, f_string: &ast::FString) {
+    for element in &f_string.elements {
+        let FStringElement::Expression(expression_element) = element else {
+            continue;
+        };
+        let Some(debug_text) = expression_element.debug_text.as_ref() else {
+            continue;
+        };
+
+        let mut diagnostic = Diagnostic::new(FStringDebugSpecifier, expression_element.range());
+
+        // The trailing debug text is the `=` itself, along with any
+        // surrounding whitespace; anything else means the source doesn't
+        // follow the plain `{x=}` form, in which case only diagnose.
+        if debug_text.trailing.trim() == "=" {
+            let expression = expression_element.expression.as_ref();
+            let trailing = Edit::range_deletion(TextRange::at(
+                expression.end(),
+                TextSize::of(debug_text.trailing.as_str()),
+            ));
+            let fix = if debug_text.leading.is_empty() {
+                Fix::unsafe_edit(trailing)
+            } else {
+                let leading = Edit::range_deletion(TextRange::new(
+                    expression.start() - TextSize::of(debug_text.leading.as_str()),
+                    expression.start(),
+                ));
+                Fix::unsafe_edits(leading, [trailing])
+            };
+            diagnostic.set_fix(fix);
+        }
+
+        checker.diagnostics.push(diagnostic);
+    }
+}
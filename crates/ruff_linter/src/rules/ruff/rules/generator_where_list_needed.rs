@@ -0,0 +1,89 @@
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Expr};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for generator expressions passed to functions that require a
+/// materialized sequence.
+///
+/// ## Why is this bad?
+/// Functions like `random.choice` require a sequence that supports `len` and
+/// indexing; passing a generator expression raises a `TypeError` at runtime.
+///
+/// ## Example
+/// ```python
+/// import random
+///
+/// random.choice(x for x in range(10))
+/// ```
+///
+/// Use instead:
+/// ```python
+/// import random
+///
+/// random.choice(list(x for x in range(10)))
+/// ```
+///
+/// ## Fix safety
+/// This rule's fix is marked as unsafe, as the original call raises a
+/// `TypeError`, so wrapping the argument in `list(...)` changes the program's
+/// behavior.
+#[violation]
+pub struct GeneratorWhereListNeeded {
+    name: String,
+}
+
+impl AlwaysFixableViolation for GeneratorWhereListNeeded {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let GeneratorWhereListNeeded { name } = self;
+        format!("`{name}` requires a sequence, but a generator was passed")
+    }
+
+    fn fix_title(&self) -> String {
+        "Wrap the generator in `list(...)`".to_string()
+    }
+}
+
+/// Functions known to require a materialized sequence as their first argument.
+const SEQUENCE_FUNCTIONS: &[&[&str]] = &[
+    &["random", "choice"],
+    &["random", "choices"],
+    &["random", "sample"],
+    &["random", "shuffle"],
+    &["json", "dumps"],
+];
+
+/// RUF053
+pub(crate) fn generator_where_list_needed(checker: &mut Checker, call: &ast::ExprCall) {
+    let Some(argument) = call.arguments.args.first() else {
+        return;
+    };
+    if !argument.is_generator_expr() {
+        return;
+    }
+    let Some(qualified_name) = checker.semantic().resolve_qualified_name(&call.func) else {
+        return;
+    };
+    if !SEQUENCE_FUNCTIONS
+        .iter()
+        .any(|target| qualified_name.segments() == *target)
+    {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(
+        GeneratorWhereListNeeded {
+            name: qualified_name.to_string(),
+        },
+        argument.range(),
+    );
+    diagnostic.set_fix(Fix::unsafe_edit(Edit::range_replacement(
+        format!("list({})", checker.locator().slice(argument)),
+        argument.range(),
+    )));
+    checker.diagnostics.push(diagnostic);
+}
@@ -0,0 +1,164 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::comparable::ComparableExpr;
+use ruff_python_ast::{self as ast, Expr, Stmt};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `if hasattr(...)` branches that select between an attribute
+/// and a fallback value, which `getattr` expresses directly.
+///
+/// ## Why is this bad?
+/// The pattern:
+/// ```python
+/// if hasattr(obj, "attr"):
+///     x = obj.attr
+/// else:
+///     x = default
+/// ```
+/// performs the attribute lookup twice, and takes five lines to express what
+/// `getattr` does in one:
+/// ```python
+/// x = getattr(obj, "attr", default)
+/// ```
+///
+/// ## Fix safety
+/// This rule's fix is marked as unsafe, as `getattr` always evaluates the
+/// default, while the `else` branch only runs when the attribute is missing;
+/// the rewrite changes behavior if the default has side effects.
+#[violation]
+pub struct HasattrThenGetattr {
+    object: String,
+}
+
+impl Violation for HasattrThenGetattr {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let HasattrThenGetattr { object } = self;
+        format!("Use `getattr({object}, ...)` with a default instead of branching on `hasattr`")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some(format!("Replace with `getattr`"))
+    }
+}
+
+/// The shape shared by both branches: an assignment to a common target, or a
+/// `return`.
+enum Branch<'a> {
+    Assign { target: &'a Expr, value: &'a Expr },
+    Return { value: &'a Expr },
+}
+
+fn match_branch(body: &[Stmt]) -> Option<Branch> {
+    match body {
+        [Stmt::Assign(ast::StmtAssign { targets, value, .. })] => {
+            let [target] = targets.as_slice() else {
+                return None;
+            };
+            Some(Branch::Assign { target, value })
+        }
+        [Stmt::Return(ast::StmtReturn {
+            value: Some(value),
+            range: _,
+        })] => Some(Branch::Return { value }),
+        _ => None,
+    }
+}
+
+/// RUF040
+pub(crate) fn hasattr_then_getattr(checker: &mut Checker, if_stmt: &ast::StmtIf) {
+    // Require an exact `if`/`else` pair, with no `elif` arms.
+    let [else_clause] = if_stmt.elif_else_clauses.as_slice() else {
+        return;
+    };
+    if else_clause.test.is_some() {
+        return;
+    }
+
+    let Expr::Call(ast::ExprCall {
+        func, arguments, ..
+    }) = if_stmt.test.as_ref()
+    else {
+        return;
+    };
+    if !arguments.keywords.is_empty() {
+        return;
+    }
+    let [object, name] = arguments.args.as_ref() else {
+        return;
+    };
+    // Restrict the receiver to a name, to guarantee that folding the lookup
+    // into a single `getattr` call doesn't duplicate side effects.
+    let Expr::Name(_) = object else {
+        return;
+    };
+    let Expr::StringLiteral(name_literal) = name else {
+        return;
+    };
+    if !checker.semantic().match_builtin_expr(func, "hasattr") {
+        return;
+    }
+
+    let (Some(then), Some(orelse)) = (match_branch(&if_stmt.body), match_branch(&else_clause.body))
+    else {
+        return;
+    };
+
+    // Both branches must take the same form, and the `if` branch must read
+    // exactly the attribute that `hasattr` tested.
+    let (attribute, default, template): (&Expr, &Expr, _) = match (&then, &orelse) {
+        (
+            Branch::Assign { target, value },
+            Branch::Assign {
+                target: else_target,
+                value: default,
+            },
+        ) => {
+            if ComparableExpr::from(*target) != ComparableExpr::from(*else_target) {
+                return;
+            }
+            (value, default, Some(*target))
+        }
+        (Branch::Return { value }, Branch::Return { value: default }) => (value, default, None),
+        _ => return,
+    };
+    let Expr::Attribute(ast::ExprAttribute { value, attr, .. }) = attribute else {
+        return;
+    };
+    if attr.as_str() != name_literal.value.to_str()
+        || ComparableExpr::from(value.as_ref()) != ComparableExpr::from(object)
+    {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(
+        HasattrThenGetattr {
+            object: checker.locator().slice(object).to_string(),
+        },
+        if_stmt.test.range(),
+    );
+
+    if !checker.indexer().has_comments(if_stmt, checker.locator()) {
+        let getattr_call = format!(
+            "getattr({}, {}, {})",
+            checker.locator().slice(object),
+            checker.locator().slice(name),
+            checker.locator().slice(default)
+        );
+        let contents = match template {
+            Some(target) => format!("{} = {getattr_call}", checker.locator().slice(target)),
+            None => format!("return {getattr_call}"),
+        };
+        diagnostic.set_fix(Fix::unsafe_edit(Edit::range_replacement(
+            contents,
+            if_stmt.range(),
+        )));
+    }
+
+    checker.diagnostics.push(diagnostic);
+}
@@ -0,0 +1,68 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::Expr;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `os.system` calls whose return value is discarded.
+///
+/// ## Why is this bad?
+/// `os.system` reports the exit status of the command via its return value,
+/// and never raises an exception when the command fails. Discarding the
+/// return value silently ignores any failure.
+///
+/// Instead, use `subprocess.run` with `check=True`, which raises a
+/// `CalledProcessError` when the command exits with a non-zero status.
+///
+/// Calls whose return value is captured (e.g., assigned to a variable) are
+/// not flagged.
+///
+/// ## Example
+/// ```python
+/// import os
+///
+/// os.system("make all")
+/// ```
+///
+/// Use instead:
+/// ```python
+/// import subprocess
+///
+/// subprocess.run(["make", "all"], check=True)
+/// ```
+///
+/// ## References
+/// - [Python documentation: `os.system`](https://docs.python.org/3/library/os.html#os.system)
+/// - [Python documentation: `subprocess.run`](https://docs.python.org/3/library/subprocess.html#subprocess.run)
+#[violation]
+pub struct IgnoredProcessReturnCode;
+
+impl Violation for IgnoredProcessReturnCode {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!(
+            "`os.system` return code is ignored; use `subprocess.run(..., check=True)` to raise on failure"
+        )
+    }
+}
+
+/// RUF074
+pub(crate) fn ignored_process_return_code(checker: &mut Checker, value: &Expr) {
+    let Expr::Call(call) = value else {
+        return;
+    };
+
+    if !checker
+        .semantic()
+        .resolve_qualified_name(&call.func)
+        .is_some_and(|qualified_name| matches!(qualified_name.segments(), ["os", "system"]))
+    {
+        return;
+    }
+
+    checker
+        .diagnostics
+        .push(Diagnostic::new(IgnoredProcessReturnCode, call.range()));
+}
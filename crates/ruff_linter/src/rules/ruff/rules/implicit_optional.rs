@@ -209,7 +209,14 @@ pub(crate) fn implicit_optional(checker: &mut Checker, parameters: &Parameters)
             ) else {
                 continue;
             };
-            let conversion_type = checker.settings.target_version.into();
+            // The `|` operator isn't available at runtime before Python 3.10, but
+            // `from __future__ import annotations` makes the annotation a string, so
+            // the operator can be used regardless of the target version.
+            let conversion_type = if checker.semantic().future_annotations_or_stub() {
+                ConversionType::BinOpOr
+            } else {
+                checker.settings.target_version.into()
+            };
 
             let mut diagnostic =
                 Diagnostic::new(ImplicitOptional { conversion_type }, expr.range());
@@ -0,0 +1,75 @@
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Expr, Operator};
+use ruff_text_size::{Ranged, TextRange};
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for string and f-string literals joined with the `+` operator on a
+/// single line.
+///
+/// ## Why is this bad?
+/// Adjacent string literals are concatenated implicitly; the `+` operator
+/// adds a runtime concatenation (and visual noise) for a value that's fully
+/// known when the code is written. This also applies when one side is an
+/// f-string: `f"{x}" "suffix"` concatenates implicitly.
+///
+/// Multi-line concatenations are covered by `explicit-string-concatenation`
+/// (`ISC003`).
+///
+/// ## Example
+/// ```python
+/// greeting = f"Hello, {name}" + "!"
+/// ```
+///
+/// Use instead:
+/// ```python
+/// greeting = f"Hello, {name}" "!"
+/// ```
+#[violation]
+pub struct ImplicitStringConcatenationPreferred;
+
+impl AlwaysFixableViolation for ImplicitStringConcatenationPreferred {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("String literals joined with `+`; use implicit concatenation")
+    }
+
+    fn fix_title(&self) -> String {
+        "Remove the `+` operator".to_string()
+    }
+}
+
+/// RUF042
+pub(crate) fn implicit_string_concatenation_preferred(checker: &mut Checker, expr: &Expr) {
+    let Expr::BinOp(ast::ExprBinOp {
+        left,
+        op: Operator::Add,
+        right,
+        range,
+    }) = expr
+    else {
+        return;
+    };
+
+    // Only plain strings and f-strings concatenate implicitly with each
+    // other; leave bytes (and anything non-literal) alone.
+    if !matches!(left.as_ref(), Expr::FString(_) | Expr::StringLiteral(_))
+        || !matches!(right.as_ref(), Expr::FString(_) | Expr::StringLiteral(_))
+    {
+        return;
+    }
+
+    // The multi-line form is ISC003's territory.
+    if checker.locator().contains_line_break(*range) {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(ImplicitStringConcatenationPreferred, expr.range());
+    diagnostic.set_fix(Fix::safe_edit(Edit::range_replacement(
+        " ".to_string(),
+        TextRange::new(left.end(), right.start()),
+    )));
+    checker.diagnostics.push(diagnostic);
+}
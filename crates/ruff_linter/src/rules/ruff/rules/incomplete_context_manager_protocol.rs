@@ -0,0 +1,107 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Stmt};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for classes that define `__enter__` without `__exit__`, or vice
+/// versa, as well as the async variants `__aenter__` and `__aexit__`.
+///
+/// ## Why is this bad?
+/// Both methods are required for a class to support the context manager
+/// protocol. A class that defines only one of the pair raises an
+/// `AttributeError` when used in a `with` (or `async with`) statement.
+///
+/// Classes with base classes are exempt, as the missing method may be
+/// inherited.
+///
+/// ## Example
+/// ```python
+/// class Resource:
+///     def __enter__(self):
+///         return self
+/// ```
+///
+/// Use instead:
+/// ```python
+/// class Resource:
+///     def __enter__(self):
+///         return self
+///
+///     def __exit__(self, exc_type, exc_value, traceback):
+///         ...
+/// ```
+///
+/// ## References
+/// - [Python documentation: Context Manager Types](https://docs.python.org/3/library/stdtypes.html#typecontextmanager)
+#[violation]
+pub struct IncompleteContextManagerProtocol {
+    name: String,
+    defined: &'static str,
+    missing: &'static str,
+}
+
+impl Violation for IncompleteContextManagerProtocol {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let IncompleteContextManagerProtocol {
+            name,
+            defined,
+            missing,
+        } = self;
+        format!("Class `{name}` defines `{defined}` without `{missing}`")
+    }
+}
+
+/// RUF072
+pub(crate) fn incomplete_context_manager_protocol(
+    checker: &mut Checker,
+    class_def: &ast::StmtClassDef,
+) {
+    // The missing method may be provided by a base class.
+    if class_def
+        .bases()
+        .iter()
+        .any(|base| !checker.semantic().match_builtin_expr(base, "object"))
+    {
+        return;
+    }
+
+    let mut enter = false;
+    let mut exit = false;
+    let mut aenter = false;
+    let mut aexit = false;
+    for statement in &class_def.body {
+        let Stmt::FunctionDef(ast::StmtFunctionDef { name, .. }) = statement else {
+            continue;
+        };
+        match name.as_str() {
+            "__enter__" => enter = true,
+            "__exit__" => exit = true,
+            "__aenter__" => aenter = true,
+            "__aexit__" => aexit = true,
+            _ => {}
+        }
+    }
+
+    for (defined, missing) in [
+        (enter && !exit).then_some(("__enter__", "__exit__")),
+        (exit && !enter).then_some(("__exit__", "__enter__")),
+        (aenter && !aexit).then_some(("__aenter__", "__aexit__")),
+        (aexit && !aenter).then_some(("__aexit__", "__aenter__")),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        checker.diagnostics.push(Diagnostic::new(
+            IncompleteContextManagerProtocol {
+                name: class_def.name.to_string(),
+                defined,
+                missing,
+            },
+            class_def.name.range(),
+        ));
+    }
+}
@@ -0,0 +1,215 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::statement_visitor::{walk_body, walk_stmt, StatementVisitor};
+use ruff_python_ast::visitor::{walk_expr, Visitor};
+use ruff_python_ast::{self as ast, Expr, Operator, Stmt, Suite};
+use ruff_text_size::{Ranged, TextRange};
+
+use crate::checkers::ast::Checker;
+use crate::rules::ruff::settings::OptionalStyle;
+use crate::settings::types::PythonVersion;
+
+/// ## What it does
+/// Checks for files that mix the `Optional[X]` and `X | None` annotation
+/// styles.
+///
+/// ## Why is this bad?
+/// Both forms express the same type. Mixing them within one file is
+/// inconsistent, and makes annotations harder to scan. This rule only fires
+/// when both styles are present, and flags the occurrences of whichever style
+/// is not preferred (as per [`lint.ruff.optional-style`]).
+///
+/// ## Example
+/// ```python
+/// def f(x: Optional[int], y: str | None) -> None: ...
+/// ```
+///
+/// Use instead (with the default `pep604` preference):
+/// ```python
+/// def f(x: int | None, y: str | None) -> None: ...
+/// ```
+///
+/// ## Fix safety
+/// The fix rewrites `Optional[X]` to `X | None`, and is only offered when the
+/// target Python version (or `from __future__ import annotations`) permits
+/// the PEP 604 form.
+///
+/// ## Options
+/// - `lint.ruff.optional-style`
+#[violation]
+pub struct InconsistentOptionalStyle {
+    preferred: OptionalStyle,
+}
+
+impl Violation for InconsistentOptionalStyle {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        match self.preferred {
+            OptionalStyle::Pep604 => {
+                format!("File mixes optional annotation styles; use `X | None` consistently")
+            }
+            OptionalStyle::Optional => {
+                format!("File mixes optional annotation styles; use `Optional[X]` consistently")
+            }
+        }
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        match self.preferred {
+            OptionalStyle::Pep604 => Some(format!("Rewrite as `X | None`")),
+            OptionalStyle::Optional => None,
+        }
+    }
+}
+
+/// An `Optional[X]` or `X | None` occurrence within an annotation.
+enum Occurrence {
+    /// An `Optional[X]` annotation, along with the range of the inner type.
+    Optional { range: TextRange, inner: TextRange },
+    /// An `X | None` annotation.
+    Pep604 { range: TextRange },
+}
+
+/// Returns `true` if the expression is a reference to `Optional`, like
+/// `Optional` or `typing.Optional`.
+///
+/// The scan runs before name resolution, so the match is syntactic; this
+/// mirrors how `Optional` is conventionally spelled in annotations.
+fn is_optional_reference(expr: &Expr) -> bool {
+    match expr {
+        Expr::Name(ast::ExprName { id, .. }) => id == "Optional",
+        Expr::Attribute(ast::ExprAttribute { attr, .. }) => attr == "Optional",
+        _ => false,
+    }
+}
+
+/// Collects optional-style occurrences from every annotation in the module,
+/// without descending into non-annotation expressions.
+#[derive(Default)]
+struct AnnotationVisitor {
+    occurrences: Vec<Occurrence>,
+}
+
+impl AnnotationVisitor {
+    fn visit_annotation(&mut self, annotation: &Expr) {
+        self.visit_expr(annotation);
+    }
+}
+
+impl StatementVisitor<'_> for AnnotationVisitor {
+    fn visit_stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::FunctionDef(function_def) => {
+                for parameter in function_def.parameters.iter() {
+                    if let Some(annotation) = parameter.annotation() {
+                        self.visit_annotation(annotation);
+                    }
+                }
+                if let Some(returns) = function_def.returns.as_deref() {
+                    self.visit_annotation(returns);
+                }
+            }
+            Stmt::AnnAssign(ast::StmtAnnAssign { annotation, .. }) => {
+                self.visit_annotation(annotation);
+            }
+            _ => {}
+        }
+        walk_stmt(self, stmt);
+    }
+}
+
+impl Visitor<'_> for AnnotationVisitor {
+    fn visit_expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Subscript(ast::ExprSubscript { value, slice, .. })
+                if is_optional_reference(value) =>
+            {
+                self.occurrences.push(Occurrence::Optional {
+                    range: expr.range(),
+                    inner: slice.range(),
+                });
+                self.visit_expr(slice);
+            }
+            Expr::BinOp(ast::ExprBinOp {
+                op: Operator::BitOr,
+                left,
+                right,
+                ..
+            }) => {
+                if left.is_none_literal_expr() || right.is_none_literal_expr() {
+                    self.occurrences.push(Occurrence::Pep604 {
+                        range: expr.range(),
+                    });
+                }
+                self.visit_expr(left);
+                self.visit_expr(right);
+            }
+            _ => walk_expr(self, expr),
+        }
+    }
+}
+
+/// Returns `true` if the module imports `annotations` from `__future__`.
+fn has_future_annotations(suite: &Suite) -> bool {
+    suite.iter().any(|stmt| {
+        stmt.as_import_from_stmt().is_some_and(|import_from| {
+            import_from.module.as_deref() == Some("__future__")
+                && import_from
+                    .names
+                    .iter()
+                    .any(|alias| alias.name.as_str() == "annotations")
+        })
+    })
+}
+
+/// RUF036
+pub(crate) fn inconsistent_optional_style(checker: &mut Checker, suite: &Suite) {
+    let occurrences = {
+        let mut visitor = AnnotationVisitor::default();
+        walk_body(&mut visitor, suite);
+        visitor.occurrences
+    };
+
+    let has_optional = occurrences
+        .iter()
+        .any(|occurrence| matches!(occurrence, Occurrence::Optional { .. }));
+    let has_pep604 = occurrences
+        .iter()
+        .any(|occurrence| matches!(occurrence, Occurrence::Pep604 { .. }));
+
+    // Only report when the file actually mixes both styles.
+    if !(has_optional && has_pep604) {
+        return;
+    }
+
+    let preferred = checker.settings.ruff.optional_style;
+    let pep604_allowed = checker.settings.target_version >= PythonVersion::Py310
+        || checker.source_type.is_stub()
+        || has_future_annotations(suite);
+
+    for occurrence in occurrences {
+        match (preferred, occurrence) {
+            (OptionalStyle::Pep604, Occurrence::Optional { range, inner }) => {
+                let mut diagnostic =
+                    Diagnostic::new(InconsistentOptionalStyle { preferred }, range);
+                if pep604_allowed {
+                    let inner_text = checker.locator().slice(inner);
+                    diagnostic.set_fix(Fix::safe_edit(Edit::range_replacement(
+                        format!("{inner_text} | None"),
+                        range,
+                    )));
+                }
+                checker.diagnostics.push(diagnostic);
+            }
+            (OptionalStyle::Optional, Occurrence::Pep604 { range }) => {
+                checker.diagnostics.push(Diagnostic::new(
+                    InconsistentOptionalStyle { preferred },
+                    range,
+                ));
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::identifier::Identifier;
+use ruff_python_ast::visitor::preorder;
+use ruff_python_ast::{self as ast, AnyNodeRef, Expr, Stmt};
+use ruff_python_semantic::SemanticModel;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for functions that contain a `yield` but whose return annotation
+/// rules out their being a generator.
+///
+/// ## Why is this bad?
+/// A function containing a `yield` always returns a generator (or an async
+/// generator), regardless of any `return` statements it may contain.
+/// Annotating such a function as returning `None` (or another scalar type)
+/// contradicts its runtime behavior and will mislead both readers and type
+/// checkers.
+///
+/// ## Example
+/// ```python
+/// def f() -> None:
+///     yield 1
+/// ```
+///
+/// Use instead:
+/// ```python
+/// from collections.abc import Iterator
+///
+///
+/// def f() -> Iterator[int]:
+///     yield 1
+/// ```
+///
+/// ## References
+/// - [Python documentation: Generators](https://docs.python.org/3/glossary.html#term-generator)
+#[violation]
+pub struct MisannotatedGenerator {
+    annotation: String,
+}
+
+impl Violation for MisannotatedGenerator {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let MisannotatedGenerator { annotation } = self;
+        format!("Function contains `yield`, but is annotated as returning `{annotation}`")
+    }
+}
+
+#[derive(Default)]
+struct YieldVisitor {
+    found_yield: bool,
+}
+
+/// Traverse a function's body to find whether it contains a `yield` or
+/// `yield from` expression. The bodies of inner functions and lambdas aren't
+/// traversed, as their `yield`s belong to the inner scope.
+impl<'a> preorder::PreorderVisitor<'a> for YieldVisitor {
+    fn enter_node(&mut self, _node: AnyNodeRef<'a>) -> preorder::TraversalSignal {
+        if self.found_yield {
+            preorder::TraversalSignal::Skip
+        } else {
+            preorder::TraversalSignal::Traverse
+        }
+    }
+
+    fn visit_stmt(&mut self, stmt: &'a Stmt) {
+        match stmt {
+            Stmt::FunctionDef(_) | Stmt::ClassDef(_) => {}
+            _ => preorder::walk_stmt(self, stmt),
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'a Expr) {
+        match expr {
+            Expr::Yield(_) | Expr::YieldFrom(_) => {
+                self.found_yield = true;
+            }
+            Expr::Lambda(_) => {}
+            _ => preorder::walk_expr(self, expr),
+        }
+    }
+}
+
+/// Returns `true` if the annotation is `None` or a scalar builtin type, which
+/// a generator function can never return.
+fn is_non_generator_annotation(annotation: &Expr, semantic: &SemanticModel) -> bool {
+    match annotation {
+        Expr::NoneLiteral(_) => true,
+        Expr::Name(name) => {
+            matches!(name.id.as_str(), "bool" | "int" | "float" | "str" | "bytes")
+                && semantic.has_builtin_binding(name.id.as_str())
+        }
+        _ => false,
+    }
+}
+
+/// RUF030
+pub(crate) fn misannotated_generator(checker: &mut Checker, function_def: &ast::StmtFunctionDef) {
+    let Some(returns) = function_def.returns.as_deref() else {
+        return;
+    };
+
+    if !is_non_generator_annotation(returns, checker.semantic()) {
+        return;
+    }
+
+    let found_yield = {
+        let mut visitor = YieldVisitor::default();
+        preorder::walk_body(&mut visitor, &function_def.body);
+        visitor.found_yield
+    };
+
+    if found_yield {
+        checker.diagnostics.push(Diagnostic::new(
+            MisannotatedGenerator {
+                annotation: checker.generator().expr(returns),
+            },
+            function_def.identifier(),
+        ));
+    }
+}
@@ -0,0 +1,139 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::visitor::{walk_expr, Visitor};
+use ruff_python_ast::{self as ast, Expr, Stmt};
+use ruff_python_semantic::SemanticModel;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for subclass `__init__` methods that never call the base class's
+/// `__init__`.
+///
+/// ## Why is this bad?
+/// If a base class defines `__init__`, it likely performs setup (attribute
+/// initialization, resource acquisition) that the subclass depends on.
+/// Overriding `__init__` without calling `super().__init__(...)` silently
+/// skips that setup, leaving instances in a partially initialized state.
+///
+/// Only base classes defined in the same file are checked; bases imported
+/// from other modules cannot be inspected and are skipped.
+///
+/// ## Example
+/// ```python
+/// class Base:
+///     def __init__(self):
+///         self.resource = acquire()
+///
+///
+/// class Derived(Base):
+///     def __init__(self):
+///         self.extra = 1
+/// ```
+///
+/// Use instead:
+/// ```python
+/// class Base:
+///     def __init__(self):
+///         self.resource = acquire()
+///
+///
+/// class Derived(Base):
+///     def __init__(self):
+///         super().__init__()
+///         self.extra = 1
+/// ```
+#[violation]
+pub struct MissingSuperInitCall {
+    base: String,
+}
+
+impl Violation for MissingSuperInitCall {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let MissingSuperInitCall { base } = self;
+        format!(
+            "`__init__` does not call `super().__init__()`, skipping setup in base class `{base}`"
+        )
+    }
+}
+
+/// RUF063
+pub(crate) fn missing_super_init_call(checker: &mut Checker, class_def: &ast::StmtClassDef) {
+    let Some(init) = find_init(&class_def.body) else {
+        return;
+    };
+    let Some(base) = class_def.bases().iter().find_map(|base| {
+        let Expr::Name(name) = base else {
+            return None;
+        };
+        let base_class = resolve_in_file_class(name, checker.semantic())?;
+        find_init(&base_class.body)
+            .is_some()
+            .then(|| name.id.as_str())
+    }) else {
+        return;
+    };
+    let mut visitor = InitCallVisitor { found: false };
+    for stmt in &init.body {
+        visitor.visit_stmt(stmt);
+    }
+    if visitor.found {
+        return;
+    }
+    checker.diagnostics.push(Diagnostic::new(
+        MissingSuperInitCall {
+            base: base.to_string(),
+        },
+        init.name.range,
+    ));
+}
+
+/// Return the `__init__` method defined in the given class body, if any.
+fn find_init(body: &[Stmt]) -> Option<&ast::StmtFunctionDef> {
+    body.iter().find_map(|stmt| match stmt {
+        Stmt::FunctionDef(function_def) if function_def.name.as_str() == "__init__" => {
+            Some(function_def)
+        }
+        _ => None,
+    })
+}
+
+/// Resolve the name to a class defined in the same file, if possible.
+fn resolve_in_file_class<'a>(
+    name: &ast::ExprName,
+    semantic: &'a SemanticModel,
+) -> Option<&'a ast::StmtClassDef> {
+    let binding_id = semantic.resolve_name(name)?;
+    let binding = semantic.binding(binding_id);
+    if !binding.kind.is_class_definition() {
+        return None;
+    }
+    match binding.statement(semantic) {
+        Some(Stmt::ClassDef(class_def)) => Some(class_def),
+        _ => None,
+    }
+}
+
+/// Visitor searching for an `....__init__(...)` call, covering both
+/// `super().__init__()` and an explicit `Base.__init__(self)`.
+struct InitCallVisitor {
+    found: bool,
+}
+
+impl<'a> Visitor<'a> for InitCallVisitor {
+    fn visit_expr(&mut self, expr: &'a Expr) {
+        if self.found {
+            return;
+        }
+        if let Expr::Call(ast::ExprCall { func, .. }) = expr {
+            if let Expr::Attribute(ast::ExprAttribute { attr, .. }) = func.as_ref() {
+                if attr == "__init__" {
+                    self.found = true;
+                    return;
+                }
+            }
+        }
+        walk_expr(self, expr);
+    }
+}
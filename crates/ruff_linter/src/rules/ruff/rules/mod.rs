@@ -1,68 +1,158 @@
 pub(crate) use ambiguous_unicode_character::*;
+pub(crate) use assert_dict_set_equality::*;
+pub(crate) use assert_message_side_effect::*;
+pub(crate) use assert_type_equality::*;
+pub(crate) use assignment_from_sort_in_place::*;
 pub(crate) use assignment_in_assert::*;
 pub(crate) use asyncio_dangling_task::*;
+pub(crate) use asyncio_gather_swallows_exceptions::*;
+pub(crate) use await_non_awaitable::*;
+pub(crate) use bytes_str_comparison::*;
 pub(crate) use collection_literal_concatenation::*;
+pub(crate) use comparison_with_range::*;
+pub(crate) use conditional_import_without_fallback::*;
+pub(crate) use conflicting_conditional_import::*;
+pub(crate) use decorator_missing_wraps::*;
+pub(crate) use deeply_nested_fstring::*;
 pub(crate) use default_factory_kwarg::*;
+pub(crate) use delete_loop_variable::*;
+pub(crate) use duplicate_decorator::*;
+pub(crate) use env_var_truthiness::*;
+pub(crate) use exit_returns_truthy::*;
 pub(crate) use explicit_f_string_type_conversion::*;
+pub(crate) use float_equality_comparison::*;
+pub(crate) use fstring_debug_specifier::*;
 pub(crate) use function_call_in_dataclass_default::*;
+pub(crate) use generator_where_list_needed::*;
+pub(crate) use hasattr_then_getattr::*;
+pub(crate) use ignored_process_return_code::*;
 pub(crate) use implicit_optional::*;
+pub(crate) use implicit_string_concatenation_preferred::*;
+pub(crate) use incomplete_context_manager_protocol::*;
+pub(crate) use inconsistent_optional_style::*;
 pub(crate) use invalid_formatter_suppression_comment::*;
 pub(crate) use invalid_index_type::*;
 pub(crate) use invalid_pyproject_toml::*;
+pub(crate) use misannotated_generator::*;
 pub(crate) use missing_fstring_syntax::*;
+pub(crate) use missing_super_init_call::*;
+pub(crate) use multiple_with_items_needs_parens::*;
 pub(crate) use mutable_class_default::*;
 pub(crate) use mutable_dataclass_default::*;
 pub(crate) use mutable_fromkeys_value::*;
 pub(crate) use never_union::*;
+pub(crate) use non_exhaustive_match::*;
 pub(crate) use pairwise_over_zipped::*;
 pub(crate) use parenthesize_logical_operators::*;
+pub(crate) use path_join_with_absolute::*;
+pub(crate) use print_to_stderr::*;
 pub(crate) use quadratic_list_summation::*;
+pub(crate) use redefined_dunder_all::*;
 pub(crate) use redirected_noqa::*;
+pub(crate) use reducible_reduce::*;
+pub(crate) use redundant_boolean_comparison::*;
+pub(crate) use redundant_parentheses_on_return::*;
+pub(crate) use redundant_type_conversion::*;
+pub(crate) use redundant_utf8_codec::*;
+pub(crate) use side_effect_in_comprehension::*;
+pub(crate) use slots_without_all_bases_slotted::*;
 pub(crate) use sort_dunder_all::*;
 pub(crate) use sort_dunder_slots::*;
 pub(crate) use static_key_dict_comprehension::*;
+pub(crate) use staticmethod_could_be_function::*;
 #[cfg(any(feature = "test-rules", test))]
 pub(crate) use test_rules::*;
 pub(crate) use unnecessary_dict_comprehension_for_iterable::*;
 pub(crate) use unnecessary_iterable_allocation_for_first_element::*;
 pub(crate) use unnecessary_key_check::*;
+pub(crate) use unnecessary_list_in_join::*;
+pub(crate) use unnecessary_return_await::*;
 pub(crate) use unused_async::*;
 pub(crate) use unused_noqa::*;
+pub(crate) use wall_clock_elapsed_time::*;
+pub(crate) use walrus_in_assert_message::*;
+pub(crate) use wrong_decorator_order::*;
+pub(crate) use yield_inside_context_manager::*;
 
 mod ambiguous_unicode_character;
+mod assert_dict_set_equality;
+mod assert_message_side_effect;
+mod assert_type_equality;
+mod assignment_from_sort_in_place;
 mod assignment_in_assert;
 mod asyncio_dangling_task;
+mod asyncio_gather_swallows_exceptions;
+mod await_non_awaitable;
+mod bytes_str_comparison;
 mod collection_literal_concatenation;
+mod comparison_with_range;
+mod conditional_import_without_fallback;
+mod conflicting_conditional_import;
 mod confusables;
+mod decorator_missing_wraps;
+mod deeply_nested_fstring;
 mod default_factory_kwarg;
+mod delete_loop_variable;
+mod duplicate_decorator;
+mod env_var_truthiness;
+mod exit_returns_truthy;
 mod explicit_f_string_type_conversion;
+mod float_equality_comparison;
+mod fstring_debug_specifier;
 mod function_call_in_dataclass_default;
+mod generator_where_list_needed;
+mod hasattr_then_getattr;
 mod helpers;
+mod ignored_process_return_code;
 mod implicit_optional;
+mod implicit_string_concatenation_preferred;
+mod incomplete_context_manager_protocol;
+mod inconsistent_optional_style;
 mod invalid_formatter_suppression_comment;
 mod invalid_index_type;
 mod invalid_pyproject_toml;
+mod misannotated_generator;
 mod missing_fstring_syntax;
+mod missing_super_init_call;
+mod multiple_with_items_needs_parens;
 mod mutable_class_default;
 mod mutable_dataclass_default;
 mod mutable_fromkeys_value;
 mod never_union;
+mod non_exhaustive_match;
 mod pairwise_over_zipped;
 mod parenthesize_logical_operators;
+mod path_join_with_absolute;
+mod print_to_stderr;
 mod quadratic_list_summation;
+mod redefined_dunder_all;
 mod redirected_noqa;
+mod reducible_reduce;
+mod redundant_boolean_comparison;
+mod redundant_parentheses_on_return;
+mod redundant_type_conversion;
+mod redundant_utf8_codec;
 mod sequence_sorting;
+mod side_effect_in_comprehension;
+mod slots_without_all_bases_slotted;
 mod sort_dunder_all;
 mod sort_dunder_slots;
 mod static_key_dict_comprehension;
+mod staticmethod_could_be_function;
 mod suppression_comment_visitor;
 #[cfg(any(feature = "test-rules", test))]
 pub(crate) mod test_rules;
 mod unnecessary_dict_comprehension_for_iterable;
 mod unnecessary_iterable_allocation_for_first_element;
 mod unnecessary_key_check;
+mod unnecessary_list_in_join;
+mod unnecessary_return_await;
 mod unused_async;
 mod unused_noqa;
+mod wall_clock_elapsed_time;
+mod walrus_in_assert_message;
+mod wrong_decorator_order;
+mod yield_inside_context_manager;
 
 #[derive(Clone, Copy)]
 pub(crate) enum Context {
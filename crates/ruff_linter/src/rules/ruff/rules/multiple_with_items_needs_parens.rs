@@ -0,0 +1,129 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast as ast;
+use ruff_python_trivia::{indentation_at_offset, SimpleTokenKind, SimpleTokenizer};
+use ruff_text_size::{Ranged, TextRange};
+
+use crate::checkers::ast::Checker;
+use crate::settings::types::PythonVersion;
+
+/// ## What it does
+/// Checks for long `with` statements that combine three or more context
+/// managers without parentheses.
+///
+/// ## Why is this bad?
+/// A `with` statement that chains several context managers on one long line
+/// is hard to read, and the items can't be split across lines without
+/// backslash continuations. As of Python 3.10, the context managers can be
+/// parenthesized and formatted one per line, like an import list.
+///
+/// ## Example
+/// ```python
+/// with open("a") as a, open("b") as b, open("c") as c, open("d") as d:
+///     ...
+/// ```
+///
+/// Use instead:
+/// ```python
+/// with (
+///     open("a") as a,
+///     open("b") as b,
+///     open("c") as c,
+///     open("d") as d,
+/// ):
+///     ...
+/// ```
+#[violation]
+pub struct MultipleWithItemsNeedsParens;
+
+impl Violation for MultipleWithItemsNeedsParens {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("`with` statement combines multiple long context managers without parentheses")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some(format!("Parenthesize the context managers"))
+    }
+}
+
+/// RUF034
+pub(crate) fn multiple_with_items_needs_parens(checker: &mut Checker, with_stmt: &ast::StmtWith) {
+    if with_stmt.items.len() < 3 {
+        return;
+    }
+
+    let Some(first) = with_stmt.items.first() else {
+        return;
+    };
+
+    // If the items are already parenthesized, the statement is fine.
+    if checker
+        .locator()
+        .slice(TextRange::new(with_stmt.start(), first.start()))
+        .contains('(')
+    {
+        return;
+    }
+
+    // Only flag statements whose header actually overflows the line length.
+    let header_line = checker.locator().full_line(with_stmt.start());
+    if header_line.trim_end().chars().count() <= checker.settings.line_length.value() as usize {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(MultipleWithItemsNeedsParens, with_stmt.range());
+
+    // The parenthesized form is only valid syntax on Python 3.10+.
+    if checker.settings.target_version >= PythonVersion::Py310 {
+        if let Some(fix) = parenthesize_items(with_stmt, checker) {
+            diagnostic.set_fix(fix);
+        }
+    }
+
+    checker.diagnostics.push(diagnostic);
+}
+
+/// Generate a [`Fix`] to rewrite the header as `with (\n    a,\n    b,\n):`.
+fn parenthesize_items(with_stmt: &ast::StmtWith, checker: &Checker) -> Option<Fix> {
+    let last = with_stmt.items.last()?;
+
+    // Find the colon that closes the header.
+    let colon = SimpleTokenizer::starts_at(last.end(), checker.locator().contents())
+        .skip_trivia()
+        .find(|token| token.kind == SimpleTokenKind::Colon)?;
+
+    let header_range = TextRange::new(with_stmt.start(), colon.end());
+
+    // Don't attempt to rearrange a header that contains comments.
+    if checker.indexer().comment_ranges().intersects(header_range) {
+        return None;
+    }
+
+    let outer_indent = indentation_at_offset(with_stmt.start(), checker.locator())?;
+    let inner_indent = format!("{outer_indent}{}", checker.stylist().indentation().as_str());
+    let line_ending = checker.stylist().line_ending().as_str();
+
+    let mut header = String::new();
+    header.push_str(if with_stmt.is_async {
+        "async with ("
+    } else {
+        "with ("
+    });
+    for item in &with_stmt.items {
+        header.push_str(line_ending);
+        header.push_str(&inner_indent);
+        header.push_str(checker.locator().slice(item));
+        header.push(',');
+    }
+    header.push_str(line_ending);
+    header.push_str(outer_indent);
+    header.push_str("):");
+
+    Some(Fix::safe_edit(Edit::range_replacement(
+        header,
+        header_range,
+    )))
+}
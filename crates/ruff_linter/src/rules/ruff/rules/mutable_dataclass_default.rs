@@ -1,12 +1,19 @@
-use ruff_python_ast::{self as ast, Stmt};
+use ruff_python_ast::helpers::map_subscript;
+use ruff_python_ast::{self as ast, Expr, Stmt};
 
-use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
 use ruff_macros::{derive_message_formats, violation};
-use ruff_python_semantic::analyze::typing::{is_immutable_annotation, is_mutable_expr};
+use ruff_python_semantic::analyze::typing::{
+    is_immutable_annotation, is_immutable_func, is_mutable_expr,
+};
+use ruff_python_semantic::SemanticModel;
 use ruff_text_size::Ranged;
 
 use crate::checkers::ast::Checker;
-use crate::rules::ruff::rules::helpers::{is_class_var_annotation, is_dataclass};
+use crate::importer::ImportRequest;
+use crate::rules::ruff::rules::helpers::{
+    is_class_var_annotation, is_dataclass, is_dataclass_field,
+};
 
 /// ## What it does
 /// Checks for mutable default values in dataclass attributes.
@@ -54,14 +61,27 @@ use crate::rules::ruff::rules::helpers::{is_class_var_annotation, is_dataclass};
 /// class A:
 ///     mutable_default: ClassVar[list[int]] = []
 /// ```
+///
+/// ## Fix safety
+/// When the default is a call to a zero-argument callable, and the attribute
+/// is annotated with a known-mutable type (like `list[int]`), this rule's fix
+/// rewrites the default to `field(default_factory=...)`. The fix is marked as
+/// unsafe, as it defers the call from class-definition time to instance
+/// creation, which changes behavior if the callable has side effects.
 #[violation]
 pub struct MutableDataclassDefault;
 
 impl Violation for MutableDataclassDefault {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
     #[derive_message_formats]
     fn message(&self) -> String {
         format!("Do not use mutable default values for dataclass attributes")
     }
+
+    fn fix_title(&self) -> Option<String> {
+        Some(format!("Replace default with `field(default_factory=...)`"))
+    }
 }
 
 /// RUF008
@@ -77,14 +97,75 @@ pub(crate) fn mutable_dataclass_default(checker: &mut Checker, class_def: &ast::
             ..
         }) = statement
         {
+            if is_class_var_annotation(annotation, checker.semantic()) {
+                continue;
+            }
+
             if is_mutable_expr(value, checker.semantic())
-                && !is_class_var_annotation(annotation, checker.semantic())
                 && !is_immutable_annotation(annotation, checker.semantic(), &[])
             {
                 checker
                     .diagnostics
                     .push(Diagnostic::new(MutableDataclassDefault, value.range()));
+            } else if let Expr::Call(ast::ExprCall {
+                func, arguments, ..
+            }) = value.as_ref()
+            {
+                // A call producing a value of a known-mutable type, like
+                // `x: list[int] = make_list()`, shares the result across all
+                // instances just like a literal default would.
+                if is_known_mutable_annotation(annotation, checker.semantic())
+                    && !is_dataclass_field(func, checker.semantic())
+                    && !is_immutable_func(func, checker.semantic(), &[])
+                {
+                    let mut diagnostic = Diagnostic::new(MutableDataclassDefault, value.range());
+                    if arguments.is_empty() {
+                        diagnostic.try_set_fix(|| {
+                            let (import_edit, binding) = checker.importer().get_or_import_symbol(
+                                &ImportRequest::import_from("dataclasses", "field"),
+                                value.start(),
+                                checker.semantic(),
+                            )?;
+                            let edit = Edit::range_replacement(
+                                format!(
+                                    "{binding}(default_factory={})",
+                                    checker.locator().slice(func.as_ref())
+                                ),
+                                value.range(),
+                            );
+                            Ok(Fix::unsafe_edits(edit, [import_edit]))
+                        });
+                    }
+                    checker.diagnostics.push(diagnostic);
+                }
             }
         }
     }
 }
+
+/// Returns `true` if the annotation names a known-mutable container type,
+/// like `list[int]` or `collections.defaultdict`.
+fn is_known_mutable_annotation(annotation: &Expr, semantic: &SemanticModel) -> bool {
+    semantic
+        .resolve_qualified_name(map_subscript(annotation))
+        .is_some_and(|qualified_name| {
+            matches!(
+                qualified_name.segments(),
+                ["" | "builtins", "list" | "dict" | "set" | "bytearray"]
+                    | [
+                        "typing",
+                        "List"
+                            | "Dict"
+                            | "Set"
+                            | "DefaultDict"
+                            | "Counter"
+                            | "Deque"
+                            | "OrderedDict"
+                    ]
+                    | [
+                        "collections",
+                        "defaultdict" | "Counter" | "deque" | "OrderedDict"
+                    ]
+            )
+        })
+}
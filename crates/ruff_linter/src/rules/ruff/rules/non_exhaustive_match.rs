@@ -0,0 +1,235 @@
+use itertools::Itertools;
+use rustc_hash::FxHashSet;
+
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::comparable::ComparableExpr;
+use ruff_python_ast::helpers::map_subscript;
+use ruff_python_ast::{self as ast, Expr, Pattern, Stmt};
+use ruff_python_semantic::analyze::class::is_enumeration;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `match` statements over an enum or `Literal` type that don't
+/// cover every member and lack a wildcard case.
+///
+/// ## Why is this bad?
+/// A `match` statement that omits some members of the matched type silently
+/// falls through when an uncovered value arrives, which often hides bugs when
+/// a new member is added. Either cover every member, or add a `case _:` that
+/// handles the remaining values — ideally by calling `typing.assert_never`,
+/// which makes the omission a type-checking error.
+///
+/// ## Example
+/// ```python
+/// match color:
+///     case Color.RED:
+///         ...
+///     case Color.GREEN:
+///         ...
+/// ```
+///
+/// Use instead:
+/// ```python
+/// match color:
+///     case Color.RED:
+///         ...
+///     case Color.GREEN:
+///         ...
+///     case _:
+///         assert_never(color)
+/// ```
+///
+/// ## References
+/// - [Python documentation: `typing.assert_never`](https://docs.python.org/3/library/typing.html#typing.assert_never)
+#[violation]
+pub struct NonExhaustiveMatch {
+    missing: String,
+}
+
+impl Violation for NonExhaustiveMatch {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let NonExhaustiveMatch { missing } = self;
+        format!("Non-exhaustive `match` is missing {missing}; add the missing cases or a wildcard `case _` that calls `typing.assert_never`")
+    }
+}
+
+/// RUF071
+pub(crate) fn non_exhaustive_match(checker: &mut Checker, match_stmt: &ast::StmtMatch) {
+    // An unguarded wildcard or capture pattern makes the `match` exhaustive.
+    if match_stmt.cases.iter().any(|case| {
+        case.guard.is_none()
+            && matches!(
+                case.pattern,
+                Pattern::MatchAs(ast::PatternMatchAs { pattern: None, .. })
+            )
+    }) {
+        return;
+    }
+
+    let Some(missing) = missing_enum_members(match_stmt, checker)
+        .or_else(|| missing_literal_values(match_stmt, checker))
+    else {
+        return;
+    };
+    if missing.is_empty() {
+        return;
+    }
+
+    checker.diagnostics.push(Diagnostic::new(
+        NonExhaustiveMatch {
+            missing: missing
+                .iter()
+                .map(|member| format!("`{member}`"))
+                .join(", "),
+        },
+        match_stmt.subject.range(),
+    ));
+}
+
+/// If every `case` matches a member of a single enum class, return the members
+/// of that class that are not covered.
+fn missing_enum_members(match_stmt: &ast::StmtMatch, checker: &Checker) -> Option<Vec<String>> {
+    let mut class_name: Option<&ast::ExprName> = None;
+    let mut covered: FxHashSet<&str> = FxHashSet::default();
+    for case in &match_stmt.cases {
+        collect_enum_patterns(
+            &case.pattern,
+            &mut class_name,
+            case.guard.is_none(),
+            &mut covered,
+        )?;
+    }
+
+    let class_name = class_name?;
+    let semantic = checker.semantic();
+    let binding_id = semantic.resolve_name(class_name)?;
+    let class_def = semantic
+        .binding(binding_id)
+        .statement(semantic)?
+        .as_class_def_stmt()?;
+    if !is_enumeration(class_def, semantic) {
+        return None;
+    }
+
+    Some(
+        class_def
+            .body
+            .iter()
+            .filter_map(|stmt| {
+                let Stmt::Assign(ast::StmtAssign { targets, .. }) = stmt else {
+                    return None;
+                };
+                let [Expr::Name(target)] = targets.as_slice() else {
+                    return None;
+                };
+                if target.id.starts_with('_') {
+                    return None;
+                }
+                (!covered.contains(target.id.as_str()))
+                    .then(|| format!("{}.{}", class_name.id, target.id))
+            })
+            .collect(),
+    )
+}
+
+/// Record the enum members matched by a pattern, or return `None` if the
+/// pattern is not a (possibly or-ed) enum member access.
+fn collect_enum_patterns<'a>(
+    pattern: &'a Pattern,
+    class_name: &mut Option<&'a ast::ExprName>,
+    unguarded: bool,
+    covered: &mut FxHashSet<&'a str>,
+) -> Option<()> {
+    match pattern {
+        Pattern::MatchValue(ast::PatternMatchValue { value, .. }) => {
+            let Expr::Attribute(ast::ExprAttribute { value, attr, .. }) = value.as_ref() else {
+                return None;
+            };
+            let name = value.as_name_expr()?;
+            match class_name {
+                Some(existing) if existing.id == name.id => {}
+                Some(_) => return None,
+                None => *class_name = Some(name),
+            }
+            if unguarded {
+                covered.insert(attr.as_str());
+            }
+            Some(())
+        }
+        Pattern::MatchOr(ast::PatternMatchOr { patterns, .. }) => {
+            for pattern in patterns {
+                collect_enum_patterns(pattern, class_name, unguarded, covered)?;
+            }
+            Some(())
+        }
+        _ => None,
+    }
+}
+
+/// If the subject is annotated with a `Literal` type, return the literal
+/// values that are not covered by any `case`.
+fn missing_literal_values(match_stmt: &ast::StmtMatch, checker: &Checker) -> Option<Vec<String>> {
+    let semantic = checker.semantic();
+
+    let subject = match_stmt.subject.as_name_expr()?;
+    let binding_id = semantic.resolve_name(subject)?;
+    let annotation = semantic
+        .binding(binding_id)
+        .statement(semantic)?
+        .as_ann_assign_stmt()?
+        .annotation
+        .as_ref();
+    if !semantic.match_typing_expr(map_subscript(annotation), "Literal") {
+        return None;
+    }
+
+    let slice = annotation.as_subscript_expr()?.slice.as_ref();
+    let values: Vec<&Expr> = match slice {
+        Expr::Tuple(ast::ExprTuple { elts, .. }) => elts.iter().collect(),
+        _ => vec![slice],
+    };
+
+    let mut covered: Vec<ComparableExpr> = Vec::new();
+    for case in &match_stmt.cases {
+        collect_literal_patterns(&case.pattern, case.guard.is_none(), &mut covered)?;
+    }
+
+    Some(
+        values
+            .iter()
+            .filter(|value| !covered.contains(&ComparableExpr::from(**value)))
+            .map(|value| checker.locator().slice(value.range()).to_string())
+            .collect(),
+    )
+}
+
+/// Record the literal values matched by a pattern, or return `None` if the
+/// pattern is not a (possibly or-ed) literal.
+fn collect_literal_patterns<'a>(
+    pattern: &'a Pattern,
+    unguarded: bool,
+    covered: &mut Vec<ComparableExpr<'a>>,
+) -> Option<()> {
+    match pattern {
+        Pattern::MatchValue(ast::PatternMatchValue { value, .. }) => {
+            if value.is_attribute_expr() {
+                return None;
+            }
+            if unguarded {
+                covered.push(ComparableExpr::from(value.as_ref()));
+            }
+            Some(())
+        }
+        Pattern::MatchOr(ast::PatternMatchOr { patterns, .. }) => {
+            for pattern in patterns {
+                collect_literal_patterns(pattern, unguarded, covered)?;
+            }
+            Some(())
+        }
+        _ => None,
+    }
+}
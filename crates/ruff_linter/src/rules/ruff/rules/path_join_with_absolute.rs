@@ -0,0 +1,107 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Expr, Operator};
+use ruff_python_semantic::analyze::typing;
+use ruff_python_semantic::SemanticModel;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `pathlib` path joins (via the `/` operator) in which the right
+/// operand is an absolute path.
+///
+/// ## Why is this bad?
+/// Like `os.path.join`, joining with an absolute path discards every segment
+/// to its left: `Path("a") / "/etc/passwd"` evaluates to
+/// `Path("/etc/passwd")`. This is rarely intentional and usually indicates a
+/// path that was expected to be relative.
+///
+/// ## Example
+/// ```python
+/// from pathlib import Path
+///
+/// Path("archive") / "/etc/passwd"  # Evaluates to `Path("/etc/passwd")`.
+/// ```
+///
+/// Use instead:
+/// ```python
+/// from pathlib import Path
+///
+/// Path("archive") / "etc/passwd"
+/// ```
+///
+/// ## References
+/// - [Python documentation: Pure paths](https://docs.python.org/3/library/pathlib.html#pure-paths)
+#[violation]
+pub struct PathJoinWithAbsolute;
+
+impl Violation for PathJoinWithAbsolute {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("Joining an absolute path with `/` discards the left operand")
+    }
+}
+
+/// RUF045
+pub(crate) fn path_join_with_absolute(checker: &mut Checker, bin_op: &ast::ExprBinOp) {
+    let Expr::StringLiteral(ast::ExprStringLiteral { value, .. }) = bin_op.right.as_ref() else {
+        return;
+    };
+    if !is_absolute(value.to_str()) {
+        return;
+    }
+    if !is_path(&bin_op.left, checker.semantic()) {
+        return;
+    }
+    checker
+        .diagnostics
+        .push(Diagnostic::new(PathJoinWithAbsolute, bin_op.range()));
+}
+
+/// Returns `true` if the path is absolute on POSIX (leading slash) or on
+/// Windows (leading backslash or drive letter).
+fn is_absolute(path: &str) -> bool {
+    if path.starts_with(['/', '\\']) {
+        return true;
+    }
+    let mut chars = path.chars();
+    matches!(
+        (chars.next(), chars.next(), chars.next()),
+        (Some(drive), Some(':'), Some('/' | '\\')) if drive.is_ascii_alphabetic()
+    )
+}
+
+/// Returns `true` if the expression is a `pathlib` path: a direct constructor
+/// call, a name assigned from one, or a `/` join thereof.
+fn is_path(expr: &Expr, semantic: &SemanticModel) -> bool {
+    let is_pathlib_constructor = |qualified_name: &ruff_python_ast::name::QualifiedName| {
+        matches!(
+            qualified_name.segments(),
+            [
+                "pathlib",
+                "Path"
+                    | "PosixPath"
+                    | "WindowsPath"
+                    | "PurePath"
+                    | "PurePosixPath"
+                    | "PureWindowsPath"
+            ]
+        )
+    };
+    match expr {
+        Expr::Call(ast::ExprCall { func, .. }) => semantic
+            .resolve_qualified_name(func)
+            .as_ref()
+            .is_some_and(is_pathlib_constructor),
+        Expr::BinOp(ast::ExprBinOp {
+            left,
+            op: Operator::Div,
+            ..
+        }) => is_path(left, semantic),
+        Expr::Name(_) => typing::resolve_assignment(expr, semantic)
+            .as_ref()
+            .is_some_and(is_pathlib_constructor),
+        _ => false,
+    }
+}
@@ -0,0 +1,92 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Expr, Stmt};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `print` calls writing to `sys.stderr` in library code.
+///
+/// ## Why is this bad?
+/// Writing errors and warnings directly to `sys.stderr` bypasses the
+/// `logging` framework, so the output cannot be filtered, redirected, or
+/// formatted by the application embedding the library. Use `logging.error`
+/// or `logging.warning` instead.
+///
+/// Calls under an `if __name__ == "__main__":` guard are exempt, as
+/// command-line entry points commonly report errors on standard error.
+///
+/// ## Example
+/// ```python
+/// import sys
+///
+/// print("Something went wrong", file=sys.stderr)
+/// ```
+///
+/// Use instead:
+/// ```python
+/// import logging
+///
+/// logging.error("Something went wrong")
+/// ```
+#[violation]
+pub struct PrintToStderr;
+
+impl Violation for PrintToStderr {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("`print` to `sys.stderr`; use `logging` instead")
+    }
+}
+
+/// RUF050
+pub(crate) fn print_to_stderr(checker: &mut Checker, call: &ast::ExprCall) {
+    if !checker.semantic().match_builtin_expr(&call.func, "print") {
+        return;
+    }
+    let Some(file) = call.arguments.find_keyword("file") else {
+        return;
+    };
+    if !checker
+        .semantic()
+        .resolve_qualified_name(&file.value)
+        .is_some_and(|qualified_name| matches!(qualified_name.segments(), ["sys", "stderr"]))
+    {
+        return;
+    }
+
+    // Exempt command-line entry points: any enclosing
+    // `if __name__ == "__main__":` guard.
+    if checker
+        .semantic()
+        .current_statements()
+        .any(|stmt| matches!(stmt, Stmt::If(ast::StmtIf { test, .. }) if is_main_check(test)))
+    {
+        return;
+    }
+
+    checker
+        .diagnostics
+        .push(Diagnostic::new(PrintToStderr, call.range()));
+}
+
+/// Returns `true` if an expression is an `if __name__ == "__main__":` check.
+fn is_main_check(expr: &Expr) -> bool {
+    if let Expr::Compare(ast::ExprCompare {
+        left, comparators, ..
+    }) = expr
+    {
+        if let Expr::Name(ast::ExprName { id, .. }) = left.as_ref() {
+            if id == "__name__" {
+                if let [Expr::StringLiteral(ast::ExprStringLiteral { value, .. })] = &**comparators
+                {
+                    if value == "__main__" {
+                        return true;
+                    }
+                }
+            }
+        }
+    }
+    false
+}
@@ -0,0 +1,113 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Expr, Stmt};
+use ruff_text_size::{Ranged, TextRange, TextSize};
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for modules with multiple plain assignments to `__all__`.
+///
+/// ## Why is this bad?
+/// A second `__all__ = [...]` assignment silently discards the earlier
+/// export list, which is rarely intended. To extend the list, use
+/// `__all__ += [...]` or `__all__.extend([...])`.
+///
+/// ## Example
+/// ```python
+/// __all__ = ["foo"]
+/// __all__ = ["bar"]
+/// ```
+///
+/// Use instead:
+/// ```python
+/// __all__ = ["foo", "bar"]
+/// ```
+///
+/// ## Fix safety
+/// When both assignments use list literals, a fix is offered that merges
+/// them into the later assignment. The fix is marked as unsafe, as it
+/// reorders the module's exports and drops any comments attached to the
+/// earlier assignment.
+#[violation]
+pub struct RedefinedDunderAll;
+
+impl Violation for RedefinedDunderAll {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("Redefinition of `__all__` discards the earlier export list")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Merge the `__all__` assignments".to_string())
+    }
+}
+
+/// RUF052
+pub(crate) fn redefined_dunder_all(checker: &mut Checker, stmt: &ast::StmtAssign) {
+    let [Expr::Name(target)] = stmt.targets.as_slice() else {
+        return;
+    };
+    if target.id != "__all__" {
+        return;
+    }
+
+    let semantic = checker.semantic();
+    if !semantic.current_scope().kind.is_module() {
+        return;
+    }
+
+    // The first binding in iteration order belongs to this assignment; any
+    // plain assignment behind it is being discarded.
+    let Some(previous) = semantic
+        .current_scope()
+        .get_all("__all__")
+        .map(|binding_id| semantic.binding(binding_id))
+        .skip(1)
+        .find_map(|binding| match binding.statement(semantic) {
+            Some(Stmt::Assign(assign @ ast::StmtAssign { targets, .. }))
+                if matches!(targets.as_slice(), [Expr::Name(name)] if name.id == "__all__") =>
+            {
+                Some(assign)
+            }
+            _ => None,
+        })
+    else {
+        return;
+    };
+
+    let mut diagnostic = Diagnostic::new(RedefinedDunderAll, stmt.range());
+    if let (Expr::List(previous_list), Expr::List(current_list)) =
+        (previous.value.as_ref(), stmt.value.as_ref())
+    {
+        let previous_elements = inner_slice(previous_list, checker).trim();
+        let current_elements = inner_slice(current_list, checker).trim();
+        let merged = match (previous_elements.is_empty(), current_elements.is_empty()) {
+            (true, _) => format!("[{current_elements}]"),
+            (_, true) => format!("[{previous_elements}]"),
+            (false, false) => {
+                format!(
+                    "[{previous_elements}, {current_elements}]",
+                    previous_elements = previous_elements.trim_end_matches(','),
+                )
+            }
+        };
+        diagnostic.set_fix(Fix::unsafe_edits(
+            Edit::range_replacement(merged, stmt.value.range()),
+            [Edit::range_deletion(
+                checker.locator().full_lines_range(previous.range()),
+            )],
+        ));
+    }
+    checker.diagnostics.push(diagnostic);
+}
+
+/// Return the source of the list's elements, between the brackets.
+fn inner_slice<'a>(list: &ast::ExprList, checker: &'a Checker) -> &'a str {
+    checker.locator().slice(TextRange::new(
+        list.start() + TextSize::from(1),
+        list.end() - TextSize::from(1),
+    ))
+}
@@ -0,0 +1,178 @@
+use anyhow::Result;
+
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, BoolOp, Expr};
+use ruff_python_semantic::SemanticModel;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+use crate::importer::ImportRequest;
+
+/// ## What it does
+/// Checks for `functools.reduce` calls that are equivalent to a builtin
+/// aggregation function.
+///
+/// ## Why is this bad?
+/// `reduce(operator.add, x)` is a verbose spelling of `sum(x)`; likewise,
+/// `reduce(operator.mul, x)` is `math.prod(x)`, and reductions over `or` and
+/// `and` are `any(x)` and `all(x)`. The dedicated functions are clearer and
+/// faster.
+///
+/// ## Example
+/// ```python
+/// functools.reduce(operator.add, numbers)
+/// ```
+///
+/// Use instead:
+/// ```python
+/// sum(numbers)
+/// ```
+///
+/// ## Fix safety
+/// This rule's fix is marked as unsafe, as the replacements differ from
+/// `reduce` on an empty iterable (`reduce` raises a `TypeError`, while the
+/// aggregation functions return their identity element), and `any`/`all`
+/// return a `bool` rather than the last operand.
+///
+/// Calls passing an initializer as a third argument are not flagged, as the
+/// initializer changes the semantics for empty iterables.
+#[violation]
+pub struct ReducibleReduce {
+    replacement: Replacement,
+}
+
+impl Violation for ReducibleReduce {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let ReducibleReduce { replacement } = self;
+        format!(
+            "`functools.reduce` is equivalent to `{}` here",
+            replacement.as_str()
+        )
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let ReducibleReduce { replacement } = self;
+        Some(format!("Replace with `{}`", replacement.as_str()))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum Replacement {
+    Sum,
+    Prod,
+    Any,
+    All,
+}
+
+impl Replacement {
+    fn as_str(self) -> &'static str {
+        match self {
+            Replacement::Sum => "sum",
+            Replacement::Prod => "math.prod",
+            Replacement::Any => "any",
+            Replacement::All => "all",
+        }
+    }
+}
+
+/// RUF057
+pub(crate) fn reducible_reduce(checker: &mut Checker, call: &ast::ExprCall) {
+    if !call.arguments.keywords.is_empty() {
+        return;
+    }
+    // A third argument provides an initializer, which changes the semantics
+    // for empty iterables; leave such calls alone.
+    let [function, iterable] = call.arguments.args.as_ref() else {
+        return;
+    };
+    if !checker
+        .semantic()
+        .resolve_qualified_name(&call.func)
+        .is_some_and(|qualified_name| matches!(qualified_name.segments(), ["functools", "reduce"]))
+    {
+        return;
+    }
+    let Some(replacement) = match_replacement(function, checker.semantic()) else {
+        return;
+    };
+
+    let mut diagnostic = Diagnostic::new(ReducibleReduce { replacement }, call.range());
+    diagnostic.try_set_fix(|| build_fix(replacement, iterable, call, checker));
+    checker.diagnostics.push(diagnostic);
+}
+
+/// Match the reducing function against the aggregations we can replace.
+fn match_replacement(function: &Expr, semantic: &SemanticModel) -> Option<Replacement> {
+    match function {
+        Expr::Lambda(ast::ExprLambda {
+            parameters, body, ..
+        }) => {
+            let parameters = parameters.as_ref()?;
+            if !parameters.posonlyargs.is_empty()
+                || !parameters.kwonlyargs.is_empty()
+                || parameters.vararg.is_some()
+                || parameters.kwarg.is_some()
+            {
+                return None;
+            }
+            let [first, second] = parameters.args.as_slice() else {
+                return None;
+            };
+            if first.default.is_some() || second.default.is_some() {
+                return None;
+            }
+            let Expr::BoolOp(ast::ExprBoolOp { op, values, .. }) = body.as_ref() else {
+                return None;
+            };
+            // Require exactly `a or b` / `a and b` over the two parameters.
+            let [Expr::Name(left), Expr::Name(right)] = values.as_slice() else {
+                return None;
+            };
+            if left.id != first.parameter.name.as_str()
+                || right.id != second.parameter.name.as_str()
+            {
+                return None;
+            }
+            match op {
+                BoolOp::Or => Some(Replacement::Any),
+                BoolOp::And => Some(Replacement::All),
+            }
+        }
+        _ => match semantic.resolve_qualified_name(function)?.segments() {
+            ["operator", "add"] => Some(Replacement::Sum),
+            ["operator", "mul"] => Some(Replacement::Prod),
+            _ => None,
+        },
+    }
+}
+
+/// Generate a [`Fix`] replacing the `reduce` call with the aggregation.
+fn build_fix(
+    replacement: Replacement,
+    iterable: &Expr,
+    call: &ast::ExprCall,
+    checker: &Checker,
+) -> Result<Fix> {
+    let iterable = checker.locator().slice(iterable);
+    match replacement {
+        Replacement::Prod => {
+            let (import_edit, binding) = checker.importer().get_or_import_symbol(
+                &ImportRequest::import("math", "prod"),
+                call.start(),
+                checker.semantic(),
+            )?;
+            Ok(Fix::unsafe_edits(
+                Edit::range_replacement(format!("{binding}({iterable})"), call.range()),
+                [import_edit],
+            ))
+        }
+        _ => Ok(Fix::unsafe_edit(Edit::range_replacement(
+            format!("{}({iterable})", replacement.as_str()),
+            call.range(),
+        ))),
+    }
+}
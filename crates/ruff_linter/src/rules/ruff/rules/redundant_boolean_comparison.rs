@@ -0,0 +1,111 @@
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, CmpOp, Expr};
+use ruff_python_semantic::analyze::typing;
+use ruff_python_semantic::{Binding, SemanticModel};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for comparisons of `bool`-typed variables to `True` or `False`.
+///
+/// ## Why is this bad?
+/// Comparing a value that is already known to be a boolean against a boolean
+/// literal is redundant: `cond == True` is just `cond`, and `cond == False`
+/// is `not cond`. The bare form reads more directly.
+///
+/// Unlike `true-false-comparison` (`E712`), which flags every comparison
+/// against a boolean literal, this rule only fires when the variable can be
+/// inferred to be a `bool`, which makes the rewrite behavior-preserving.
+///
+/// ## Example
+/// ```python
+/// ready: bool = is_ready()
+/// if ready == True:
+///     launch()
+/// ```
+///
+/// Use instead:
+/// ```python
+/// ready: bool = is_ready()
+/// if ready:
+///     launch()
+/// ```
+///
+/// ## Fix safety
+/// The fix is only offered when the variable's sole binding is inferred to
+/// be a `bool`, in which case dropping the comparison cannot change the
+/// result.
+#[violation]
+pub struct RedundantBooleanComparison {
+    negated: bool,
+}
+
+impl AlwaysFixableViolation for RedundantBooleanComparison {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let RedundantBooleanComparison { negated } = self;
+        if *negated {
+            format!("Redundant comparison of boolean variable; use `not cond` instead")
+        } else {
+            format!("Redundant comparison of boolean variable; use `cond` instead")
+        }
+    }
+
+    fn fix_title(&self) -> String {
+        "Remove the comparison".to_string()
+    }
+}
+
+/// RUF065
+pub(crate) fn redundant_boolean_comparison(checker: &mut Checker, compare: &ast::ExprCompare) {
+    let ([op], [comparator]) = (&*compare.ops, &*compare.comparators) else {
+        return;
+    };
+    if !matches!(op, CmpOp::Eq | CmpOp::NotEq) {
+        return;
+    }
+    // Ex) `cond == True` or `True == cond`.
+    let (variable, literal) = match (compare.left.as_ref(), comparator) {
+        (variable, Expr::BooleanLiteral(literal)) => (variable, literal),
+        (Expr::BooleanLiteral(literal), variable) => (variable, literal),
+        _ => return,
+    };
+    let Expr::Name(name) = variable else {
+        return;
+    };
+    if !single_binding(name, checker.semantic())
+        .is_some_and(|binding| typing::is_bool(binding, checker.semantic()))
+    {
+        return;
+    }
+    let negated = matches!(op, CmpOp::Eq) != literal.value;
+    let replacement = if negated {
+        format!("not {}", name.id)
+    } else {
+        name.id.to_string()
+    };
+    let mut diagnostic = Diagnostic::new(RedundantBooleanComparison { negated }, compare.range());
+    diagnostic.set_fix(Fix::safe_edit(Edit::range_replacement(
+        replacement,
+        compare.range(),
+    )));
+    checker.diagnostics.push(diagnostic);
+}
+
+/// Return the sole binding for the name in the current scope, if any.
+fn single_binding<'a>(
+    name: &ast::ExprName,
+    semantic: &'a SemanticModel,
+) -> Option<&'a Binding<'a>> {
+    let bindings: Vec<&Binding> = semantic
+        .current_scope()
+        .get_all(name.id.as_str())
+        .map(|id| semantic.binding(id))
+        .collect();
+    let [binding] = bindings.as_slice() else {
+        return None;
+    };
+    Some(binding)
+}
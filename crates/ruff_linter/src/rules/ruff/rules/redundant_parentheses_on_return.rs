@@ -0,0 +1,127 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::parenthesize::parenthesized_range;
+use ruff_python_ast::{self as ast, AnyNodeRef, Expr};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for unnecessary parentheses around a single `return` or `yield`
+/// value.
+///
+/// ## Why is this bad?
+/// Parentheses around a lone `return` or `yield` value, as in `return (x)`,
+/// add noise without changing the meaning of the statement.
+///
+/// Tuples (`return (x, y)`), generator expressions, named expressions, and
+/// nested `yield` expressions are not flagged, as their parentheses are
+/// meaningful or required. Multi-line parenthesized values are also skipped
+/// by default, as the parentheses enable line continuation; set
+/// [`lint.ruff.flag-multiline-return-parens`] to flag them too.
+///
+/// ## Example
+/// ```python
+/// def f():
+///     return (x)
+/// ```
+///
+/// Use instead:
+/// ```python
+/// def f():
+///     return x
+/// ```
+///
+/// ## Options
+/// - `lint.ruff.flag-multiline-return-parens`
+#[violation]
+pub struct RedundantParenthesesOnReturn {
+    keyword: String,
+}
+
+impl Violation for RedundantParenthesesOnReturn {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let RedundantParenthesesOnReturn { keyword } = self;
+        format!("Redundant parentheses around `{keyword}` value")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Remove the parentheses".to_string())
+    }
+}
+
+/// RUF046 (`return`)
+pub(crate) fn redundant_parentheses_on_return(checker: &mut Checker, stmt: &ast::StmtReturn) {
+    let Some(value) = stmt.value.as_deref() else {
+        return;
+    };
+    check_parentheses(checker, stmt.into(), value, "return");
+}
+
+/// RUF046 (`yield`)
+pub(crate) fn redundant_parentheses_on_yield(checker: &mut Checker, expr: &ast::ExprYield) {
+    let Some(value) = expr.value.as_deref() else {
+        return;
+    };
+    check_parentheses(checker, expr.into(), value, "yield");
+}
+
+fn check_parentheses(checker: &mut Checker, parent: AnyNodeRef, value: &Expr, keyword: &str) {
+    // Skip values whose parentheses are meaningful (tuples) or required
+    // (generator expressions, named expressions, nested `yield`s).
+    if matches!(
+        value,
+        Expr::Tuple(_)
+            | Expr::Generator(_)
+            | Expr::Named(_)
+            | Expr::Yield(_)
+            | Expr::YieldFrom(_)
+            | Expr::Starred(_)
+    ) {
+        return;
+    }
+
+    let Some(parenthesized) = parenthesized_range(
+        value.into(),
+        parent,
+        checker.indexer().comment_ranges(),
+        checker.locator().contents(),
+    ) else {
+        return;
+    };
+
+    let multiline = checker.locator().contains_line_break(parenthesized);
+    if multiline && !checker.settings.ruff.flag_multiline_return_parens {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(
+        RedundantParenthesesOnReturn {
+            keyword: keyword.to_string(),
+        },
+        parenthesized,
+    );
+    // Removing the parentheses from a multi-line value would break the line
+    // continuation, so only offer a fix for single-line values.
+    if !multiline {
+        let mut content = checker.locator().slice(value).to_string();
+        // Retain a separator in the degenerate `return(x)` case.
+        if checker
+            .locator()
+            .up_to(parenthesized.start())
+            .chars()
+            .last()
+            .is_some_and(|char| char.is_ascii_alphanumeric())
+        {
+            content.insert(0, ' ');
+        }
+        diagnostic.set_fix(Fix::safe_edit(Edit::range_replacement(
+            content,
+            parenthesized,
+        )));
+    }
+    checker.diagnostics.push(diagnostic);
+}
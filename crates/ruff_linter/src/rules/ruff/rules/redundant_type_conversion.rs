@@ -0,0 +1,124 @@
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Expr, Number};
+use ruff_python_semantic::analyze::typing;
+use ruff_python_semantic::{Binding, SemanticModel};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for calls to `int`, `str`, or `float` whose argument is already
+/// known to be of that type.
+///
+/// ## Why is this bad?
+/// Converting a value to the type it already has is a no-op, and usually
+/// indicates leftover defensive code. Removing the redundant conversion
+/// makes the intent clearer.
+///
+/// ## Example
+/// ```python
+/// count: int = 5
+/// total = int(count)
+/// ```
+///
+/// Use instead:
+/// ```python
+/// count: int = 5
+/// total = count
+/// ```
+///
+/// ## Fix safety
+/// This rule's fix is marked as unsafe, as the type of a variable is
+/// inferred from its annotation or initializer, which may not reflect its
+/// type at the call site (e.g., after a reassignment). Further, for
+/// instances of subclasses, the conversion is not a no-op (e.g., `str` of
+/// a `str` subclass returns an exact `str`).
+#[violation]
+pub struct RedundantTypeConversion {
+    conversion: String,
+}
+
+impl AlwaysFixableViolation for RedundantTypeConversion {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let RedundantTypeConversion { conversion } = self;
+        format!("Unnecessary `{conversion}` conversion of a value that is already `{conversion}`")
+    }
+
+    fn fix_title(&self) -> String {
+        let RedundantTypeConversion { conversion } = self;
+        format!("Remove the `{conversion}` call")
+    }
+}
+
+/// RUF043
+pub(crate) fn redundant_type_conversion(checker: &mut Checker, call: &ast::ExprCall) {
+    if !call.arguments.keywords.is_empty() {
+        return;
+    }
+    let [argument] = call.arguments.args.as_ref() else {
+        return;
+    };
+
+    let semantic = checker.semantic();
+    let Some(conversion) = semantic.resolve_builtin_symbol(&call.func) else {
+        return;
+    };
+
+    let redundant = match conversion {
+        "int" => match argument {
+            Expr::NumberLiteral(ast::ExprNumberLiteral { value, .. }) => {
+                matches!(value, Number::Int(_))
+            }
+            Expr::Name(name) => is_known_type(name, semantic, typing::is_int),
+            _ => false,
+        },
+        "str" => match argument {
+            Expr::StringLiteral(_) | Expr::FString(_) => true,
+            Expr::Name(name) => is_known_type(name, semantic, typing::is_str),
+            _ => false,
+        },
+        "float" => match argument {
+            Expr::NumberLiteral(ast::ExprNumberLiteral { value, .. }) => {
+                matches!(value, Number::Float(_))
+            }
+            Expr::Name(name) => is_known_type(name, semantic, typing::is_float),
+            _ => false,
+        },
+        _ => return,
+    };
+    if !redundant {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(
+        RedundantTypeConversion {
+            conversion: conversion.to_string(),
+        },
+        call.range(),
+    );
+    diagnostic.set_fix(Fix::unsafe_edit(Edit::range_replacement(
+        checker.locator().slice(argument).to_string(),
+        call.range(),
+    )));
+    checker.diagnostics.push(diagnostic);
+}
+
+/// Returns `true` if the name has exactly one binding in the current scope,
+/// and that binding is confidently of the type tested by `is_type`.
+fn is_known_type(
+    name: &ast::ExprName,
+    semantic: &SemanticModel,
+    is_type: fn(&Binding, &SemanticModel) -> bool,
+) -> bool {
+    let bindings: Vec<&Binding> = semantic
+        .current_scope()
+        .get_all(name.id.as_str())
+        .map(|id| semantic.binding(id))
+        .collect();
+    let [binding] = bindings.as_slice() else {
+        return false;
+    };
+    is_type(binding, semantic)
+}
@@ -0,0 +1,134 @@
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Fix};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Expr};
+use ruff_python_semantic::analyze::typing;
+use ruff_python_semantic::{Binding, SemanticModel};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+use crate::fix::edits::{remove_argument, Parentheses};
+
+/// ## What it does
+/// Checks for `encode` and `decode` calls that pass UTF-8 as an explicit
+/// encoding.
+///
+/// ## Why is this bad?
+/// UTF-8 is the default encoding for both `str.encode` and `bytes.decode`,
+/// so spelling it out adds noise without changing behavior. Prefer the
+/// bare `.encode()` / `.decode()` form.
+///
+/// For string literals encoded as UTF-8, see `unnecessary-encode-utf8`
+/// (`UP012`), which rewrites the call into a bytes literal.
+///
+/// ## Example
+/// ```python
+/// data.decode("utf-8")
+/// ```
+///
+/// Use instead:
+/// ```python
+/// data.decode()
+/// ```
+///
+/// ## References
+/// - [Python documentation: `str.encode`](https://docs.python.org/3/library/stdtypes.html#str.encode)
+/// - [Python documentation: `bytes.decode`](https://docs.python.org/3/library/stdtypes.html#bytes.decode)
+#[violation]
+pub struct RedundantUtf8Codec {
+    method: String,
+}
+
+impl AlwaysFixableViolation for RedundantUtf8Codec {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let RedundantUtf8Codec { method } = self;
+        format!("Redundant UTF-8 `encoding` argument to `{method}`")
+    }
+
+    fn fix_title(&self) -> String {
+        "Remove the `encoding` argument".to_string()
+    }
+}
+
+/// Encodings accepted as spellings of UTF-8, compared case-insensitively.
+const UTF8_LITERALS: &[&str] = &["utf-8", "utf8", "utf_8"];
+
+/// RUF064
+pub(crate) fn redundant_utf8_codec(checker: &mut Checker, call: &ast::ExprCall) {
+    let Expr::Attribute(ast::ExprAttribute { value, attr, .. }) = call.func.as_ref() else {
+        return;
+    };
+    let ([argument], []) = (&*call.arguments.args, &*call.arguments.keywords) else {
+        return;
+    };
+    let Expr::StringLiteral(ast::ExprStringLiteral {
+        value: encoding, ..
+    }) = argument
+    else {
+        return;
+    };
+    if !UTF8_LITERALS.contains(&encoding.to_str().to_lowercase().as_str()) {
+        return;
+    }
+    let matches = match attr.as_str() {
+        // String literals are handled by `unnecessary-encode-utf8` (`UP012`),
+        // which rewrites the entire call into a bytes literal.
+        "encode" => is_str_receiver(value, checker.semantic()),
+        "decode" => is_bytes_receiver(value, checker.semantic()),
+        _ => return,
+    };
+    if !matches {
+        return;
+    }
+    let mut diagnostic = Diagnostic::new(
+        RedundantUtf8Codec {
+            method: attr.to_string(),
+        },
+        call.range(),
+    );
+    diagnostic.try_set_fix(|| {
+        remove_argument(
+            argument,
+            &call.arguments,
+            Parentheses::Preserve,
+            checker.locator().contents(),
+        )
+        .map(Fix::safe_edit)
+    });
+    checker.diagnostics.push(diagnostic);
+}
+
+/// Return `true` if the expression is a variable known to be a `str`.
+fn is_str_receiver(expr: &Expr, semantic: &SemanticModel) -> bool {
+    let Expr::Name(name) = expr else {
+        return false;
+    };
+    single_binding(name, semantic).is_some_and(|binding| typing::is_str(binding, semantic))
+}
+
+/// Return `true` if the expression is a bytes literal or a variable known to
+/// be `bytes`.
+fn is_bytes_receiver(expr: &Expr, semantic: &SemanticModel) -> bool {
+    match expr {
+        Expr::BytesLiteral(_) => true,
+        Expr::Name(name) => single_binding(name, semantic)
+            .is_some_and(|binding| typing::is_bytes(binding, semantic)),
+        _ => false,
+    }
+}
+
+/// Return the sole binding for the name in the current scope, if any.
+fn single_binding<'a>(
+    name: &ast::ExprName,
+    semantic: &'a SemanticModel,
+) -> Option<&'a Binding<'a>> {
+    let bindings: Vec<&Binding> = semantic
+        .current_scope()
+        .get_all(name.id.as_str())
+        .map(|id| semantic.binding(id))
+        .collect();
+    let [binding] = bindings.as_slice() else {
+        return None;
+    };
+    Some(binding)
+}
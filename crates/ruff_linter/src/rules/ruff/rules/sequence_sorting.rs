@@ -33,6 +33,9 @@ pub(super) enum SortingStyle {
     /// a [natural sort](https://en.wikipedia.org/wiki/Natural_sort_order)
     /// is used to order the elements.
     Isort,
+    /// Sort string-literal items according to a case-insensitive
+    /// [natural sort](https://en.wikipedia.org/wiki/Natural_sort_order).
+    CaseInsensitive,
 }
 
 impl SortingStyle {
@@ -40,6 +43,7 @@ impl SortingStyle {
         match self {
             Self::Natural => natord::compare(a, b),
             Self::Isort => IsortSortKey::from(a).cmp(&IsortSortKey::from(b)),
+            Self::CaseInsensitive => natord::compare_ignore_case(a, b),
         }
     }
 }
@@ -0,0 +1,107 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::whitespace;
+use ruff_python_ast::{self as ast, Expr};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for list comprehensions that are evaluated for their side effects.
+///
+/// ## Why is this bad?
+/// A comprehension builds a list; using one to call a mutating method (e.g.,
+/// `[lst.append(x) for x in it]`) or to run a function whose result is
+/// discarded allocates a throwaway list of `None`s and obscures the intent.
+/// An explicit `for` loop is clearer and avoids the allocation.
+///
+/// ## Example
+/// ```python
+/// [lst.append(x) for x in items]
+/// ```
+///
+/// Use instead:
+/// ```python
+/// for x in items:
+///     lst.append(x)
+/// ```
+///
+/// ## Fix safety
+/// When the comprehension is a standalone statement, a fix is offered that
+/// rewrites it as a `for` loop. The fix is marked as unsafe, as the loop
+/// variable leaks into the enclosing scope, unlike a comprehension target.
+#[violation]
+pub struct SideEffectInComprehension;
+
+impl Violation for SideEffectInComprehension {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("Comprehension used for side effects; use a `for` loop instead")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Rewrite as a `for` loop".to_string())
+    }
+}
+
+/// Methods that mutate their receiver and return `None`.
+const MUTATOR_METHODS: &[&str] = &[
+    "add", "append", "clear", "discard", "extend", "insert", "remove", "update", "write",
+];
+
+/// RUF059
+pub(crate) fn side_effect_in_comprehension(
+    checker: &mut Checker,
+    comprehension: &ast::ExprListComp,
+) {
+    // The result is discarded if the comprehension is itself an expression
+    // statement.
+    let unused = checker.semantic().current_expression_parent().is_none()
+        && checker.semantic().current_statement().is_expr_stmt();
+
+    // Flag a mutating call anywhere, and any discarded call result.
+    let flagged = match comprehension.elt.as_ref() {
+        Expr::Call(ast::ExprCall { func, .. }) => match func.as_ref() {
+            Expr::Attribute(ast::ExprAttribute { attr, .. }) => {
+                unused || MUTATOR_METHODS.contains(&attr.as_str())
+            }
+            _ => unused,
+        },
+        _ => false,
+    };
+    if !flagged {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(SideEffectInComprehension, comprehension.range());
+    if unused {
+        if let Some(edit) = into_for_loop(comprehension, checker) {
+            diagnostic.set_fix(Fix::unsafe_edit(edit));
+        }
+    }
+    checker.diagnostics.push(diagnostic);
+}
+
+/// Rewrite a standalone single-generator comprehension as a `for` loop.
+fn into_for_loop(comprehension: &ast::ExprListComp, checker: &Checker) -> Option<Edit> {
+    let [generator] = comprehension.generators.as_slice() else {
+        return None;
+    };
+    if generator.is_async || !generator.ifs.is_empty() {
+        return None;
+    }
+    let stmt = checker.semantic().current_statement();
+    if !stmt.is_expr_stmt() || stmt.range() != comprehension.range() {
+        return None;
+    }
+    let indentation = whitespace::indentation(checker.locator(), stmt)?;
+    let target = checker.locator().slice(&generator.target);
+    let iter = checker.locator().slice(&generator.iter);
+    let elt = checker.locator().slice(comprehension.elt.as_ref());
+    Some(Edit::range_replacement(
+        format!("for {target} in {iter}:\n{indentation}    {elt}"),
+        stmt.range(),
+    ))
+}
@@ -0,0 +1,110 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Expr, Stmt};
+use ruff_python_semantic::SemanticModel;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for classes that declare `__slots__` while inheriting from a base
+/// class that does not.
+///
+/// ## Why is this bad?
+/// `__slots__` only suppresses the per-instance `__dict__` if every class in
+/// the inheritance chain defines it. Inheriting from a non-slotted base
+/// silently re-introduces the `__dict__`, negating the memory savings the
+/// `__slots__` declaration suggests.
+///
+/// Only base classes defined in the same file are checked; bases imported
+/// from other modules cannot be inspected and are skipped.
+///
+/// ## Example
+/// ```python
+/// class Base:
+///     ...
+///
+///
+/// class Slotted(Base):
+///     __slots__ = ("x",)
+/// ```
+///
+/// Use instead:
+/// ```python
+/// class Base:
+///     __slots__ = ()
+///
+///
+/// class Slotted(Base):
+///     __slots__ = ("x",)
+/// ```
+///
+/// ## References
+/// - [Python documentation: `__slots__`](https://docs.python.org/3/reference/datamodel.html#slots)
+#[violation]
+pub struct SlotsWithoutAllBasesSlotted {
+    base: String,
+}
+
+impl Violation for SlotsWithoutAllBasesSlotted {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let SlotsWithoutAllBasesSlotted { base } = self;
+        format!("`__slots__` is ineffective: base class `{base}` does not define `__slots__`")
+    }
+}
+
+/// RUF056
+pub(crate) fn slots_without_all_bases_slotted(
+    checker: &mut Checker,
+    class_def: &ast::StmtClassDef,
+) {
+    if !has_slots(&class_def.body) {
+        return;
+    }
+    for base in class_def.bases() {
+        let Expr::Name(name) = base else {
+            continue;
+        };
+        let Some(base_class) = resolve_in_file_class(name, checker.semantic()) else {
+            continue;
+        };
+        if !has_slots(&base_class.body) {
+            checker.diagnostics.push(Diagnostic::new(
+                SlotsWithoutAllBasesSlotted {
+                    base: name.id.to_string(),
+                },
+                base.range(),
+            ));
+        }
+    }
+}
+
+/// Resolve the name to a class defined in the same file, if possible.
+fn resolve_in_file_class<'a>(
+    name: &ast::ExprName,
+    semantic: &'a SemanticModel,
+) -> Option<&'a ast::StmtClassDef> {
+    let binding_id = semantic.resolve_name(name)?;
+    let binding = semantic.binding(binding_id);
+    if !binding.kind.is_class_definition() {
+        return None;
+    }
+    match binding.statement(semantic) {
+        Some(Stmt::ClassDef(class_def)) => Some(class_def),
+        _ => None,
+    }
+}
+
+/// Return `true` if the given class body contains a `__slots__` assignment.
+fn has_slots(body: &[Stmt]) -> bool {
+    body.iter().any(|stmt| match stmt {
+        Stmt::Assign(ast::StmtAssign { targets, .. }) => targets
+            .iter()
+            .any(|target| matches!(target, Expr::Name(name) if name.id == "__slots__")),
+        Stmt::AnnAssign(ast::StmtAnnAssign { target, .. }) => {
+            matches!(target.as_ref(), Expr::Name(name) if name.id == "__slots__")
+        }
+        _ => false,
+    })
+}
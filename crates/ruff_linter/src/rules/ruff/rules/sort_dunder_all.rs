@@ -77,7 +77,15 @@ impl Violation for UnsortedDunderAll {
     }
 }
 
-const SORTING_STYLE: SortingStyle = SortingStyle::Isort;
+/// The sorting style to apply, respecting the
+/// `lint.ruff.dunder-all-case-insensitive` setting.
+fn sorting_style(checker: &Checker) -> SortingStyle {
+    if checker.settings.ruff.dunder_all_case_insensitive {
+        SortingStyle::CaseInsensitive
+    } else {
+        SortingStyle::Isort
+    }
+}
 
 /// Sort an `__all__` definition represented by a `StmtAssign` AST node.
 /// For example: `__all__ = ["b", "c", "a"]`.
@@ -162,7 +170,7 @@ fn sort_dunder_all(checker: &mut Checker, target: &ast::Expr, node: &ast::Expr)
         _ => return,
     };
 
-    let elts_analysis = SortClassification::of_elements(elts, SORTING_STYLE);
+    let elts_analysis = SortClassification::of_elements(elts, sorting_style(checker));
     if elts_analysis.is_not_a_list_of_string_literals() || elts_analysis.is_sorted() {
         return;
     }
@@ -214,9 +222,15 @@ fn create_fix(
         if is_multiline {
             let value = MultilineStringSequenceValue::from_source_range(range, kind, locator)?;
             assert_eq!(value.len(), elts.len());
-            value.into_sorted_source_code(SORTING_STYLE, locator, checker.stylist())
+            value.into_sorted_source_code(sorting_style(checker), locator, checker.stylist())
         } else {
-            sort_single_line_elements_sequence(kind, elts, string_items, locator, SORTING_STYLE)
+            sort_single_line_elements_sequence(
+                kind,
+                elts,
+                string_items,
+                locator,
+                sorting_style(checker),
+            )
         }
     };
 
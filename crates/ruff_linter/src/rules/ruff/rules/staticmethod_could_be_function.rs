@@ -0,0 +1,107 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::visitor::{walk_expr, Visitor};
+use ruff_python_ast::{self as ast, Expr, Stmt};
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for static methods that make no use of their enclosing class.
+///
+/// ## Why is this bad?
+/// A `@staticmethod` that never references the class (not even to call a
+/// sibling method) gains nothing from living inside it: it complicates the
+/// class's interface and every call site pays the attribute lookup. A
+/// module-level function expresses the same thing more simply.
+///
+/// ## Example
+/// ```python
+/// class Parser:
+///     @staticmethod
+///     def normalize(text):
+///         return text.strip().lower()
+/// ```
+///
+/// Use instead:
+/// ```python
+/// def normalize(text):
+///     return text.strip().lower()
+///
+///
+/// class Parser: ...
+/// ```
+#[violation]
+pub struct StaticMethodCouldBeFunction {
+    name: String,
+}
+
+impl Violation for StaticMethodCouldBeFunction {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let StaticMethodCouldBeFunction { name } = self;
+        format!(
+            "Static method `{name}` makes no use of its class; consider a module-level function"
+        )
+    }
+}
+
+/// RUF062
+pub(crate) fn staticmethod_could_be_function(checker: &mut Checker, class_def: &ast::StmtClassDef) {
+    for stmt in &class_def.body {
+        let Stmt::FunctionDef(function_def) = stmt else {
+            continue;
+        };
+        // Require exactly the `@staticmethod` decorator; further decorators
+        // may depend on the class context.
+        let [decorator] = function_def.decorator_list.as_slice() else {
+            continue;
+        };
+        if !checker
+            .semantic()
+            .match_builtin_expr(&decorator.expression, "staticmethod")
+        {
+            continue;
+        }
+        // Dunder methods are part of the class protocol and cannot move out.
+        if function_def.name.starts_with("__") && function_def.name.ends_with("__") {
+            continue;
+        }
+        let mut visitor = ClassReferenceVisitor {
+            class_name: class_def.name.as_str(),
+            found: false,
+        };
+        for stmt in &function_def.body {
+            visitor.visit_stmt(stmt);
+        }
+        if visitor.found {
+            continue;
+        }
+        checker.diagnostics.push(Diagnostic::new(
+            StaticMethodCouldBeFunction {
+                name: function_def.name.to_string(),
+            },
+            function_def.name.range,
+        ));
+    }
+}
+
+/// Visitor searching for references to the enclosing class name.
+struct ClassReferenceVisitor<'a> {
+    class_name: &'a str,
+    found: bool,
+}
+
+impl<'a> Visitor<'a> for ClassReferenceVisitor<'_> {
+    fn visit_expr(&mut self, expr: &'a Expr) {
+        if self.found {
+            return;
+        }
+        if let Expr::Name(ast::ExprName { id, .. }) = expr {
+            if id == self.class_name {
+                self.found = true;
+                return;
+            }
+        }
+        walk_expr(self, expr);
+    }
+}
@@ -0,0 +1,99 @@
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Expr};
+use ruff_python_semantic::analyze::typing;
+use ruff_python_semantic::{Binding, SemanticModel};
+use ruff_text_size::{Ranged, TextRange, TextSize};
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `str.join` calls whose sole argument is a list comprehension.
+///
+/// ## Why is this bad?
+/// `join` consumes any iterable; materializing the elements into a list first
+/// allocates an intermediate list for no benefit. Passing a generator
+/// expression avoids the allocation.
+///
+/// ## Example
+/// ```python
+/// ", ".join([str(x) for x in items])
+/// ```
+///
+/// Use instead:
+/// ```python
+/// ", ".join(str(x) for x in items)
+/// ```
+#[violation]
+pub struct UnnecessaryListInJoin;
+
+impl AlwaysFixableViolation for UnnecessaryListInJoin {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("Unnecessary list comprehension passed to `join`")
+    }
+
+    fn fix_title(&self) -> String {
+        "Remove the brackets".to_string()
+    }
+}
+
+/// RUF060
+pub(crate) fn unnecessary_list_in_join(checker: &mut Checker, call: &ast::ExprCall) {
+    if !call.arguments.keywords.is_empty() {
+        return;
+    }
+    let [Expr::ListComp(comprehension)] = call.arguments.args.as_ref() else {
+        return;
+    };
+    // An async comprehension has no generator-expression equivalent here.
+    if comprehension
+        .generators
+        .iter()
+        .any(|generator| generator.is_async)
+    {
+        return;
+    }
+    let Expr::Attribute(ast::ExprAttribute { attr, value, .. }) = call.func.as_ref() else {
+        return;
+    };
+    if attr != "join" {
+        return;
+    }
+    if !is_str(value, checker.semantic()) {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(UnnecessaryListInJoin, comprehension.range());
+    diagnostic.set_fix(Fix::safe_edit(Edit::range_replacement(
+        checker
+            .locator()
+            .slice(TextRange::new(
+                comprehension.start() + TextSize::from(1),
+                comprehension.end() - TextSize::from(1),
+            ))
+            .to_string(),
+        comprehension.range(),
+    )));
+    checker.diagnostics.push(diagnostic);
+}
+
+/// Returns `true` if the expression is confidently str-typed: a string
+/// literal, or a name with a single str-typed binding.
+fn is_str(expr: &Expr, semantic: &SemanticModel) -> bool {
+    match expr {
+        Expr::StringLiteral(_) | Expr::FString(_) => true,
+        Expr::Name(name) => {
+            let bindings: Vec<&Binding> = semantic
+                .current_scope()
+                .get_all(name.id.as_str())
+                .map(|id| semantic.binding(id))
+                .collect();
+            let [binding] = bindings.as_slice() else {
+                return false;
+            };
+            typing::is_str(binding, semantic)
+        }
+        _ => false,
+    }
+}
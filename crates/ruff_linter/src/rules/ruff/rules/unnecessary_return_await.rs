@@ -0,0 +1,83 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Stmt};
+use ruff_text_size::{Ranged, TextRange};
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for async functions that end in `return await`.
+///
+/// ## Why is this bad?
+/// When an async function does nothing with an awaited result except return
+/// it, the `await` adds a suspension point without adding behavior: the
+/// caller can await the returned awaitable itself.
+///
+/// Note that removing the `await` is a behavioral change: exceptions raised
+/// by the awaitable then propagate from the caller's `await`, rather than
+/// from inside this function, which affects tracebacks and any enclosing
+/// `try` blocks. As such, this rule is opt-in.
+///
+/// ## Example
+/// ```python
+/// async def fetch():
+///     return await get(url)
+/// ```
+///
+/// Use instead:
+/// ```python
+/// async def fetch():
+///     return get(url)
+/// ```
+///
+/// ## Fix safety
+/// This rule's fix is marked as unsafe, as it changes where exceptions from
+/// the awaitable are raised, and requires that every caller awaits the
+/// returned value.
+#[violation]
+pub struct UnnecessaryReturnAwait;
+
+impl Violation for UnnecessaryReturnAwait {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("Unnecessary `await` in terminal `return` statement")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some(format!("Remove `await`"))
+    }
+}
+
+/// RUF039
+pub(crate) fn unnecessary_return_await(checker: &mut Checker, function_def: &ast::StmtFunctionDef) {
+    if !function_def.is_async {
+        return;
+    }
+
+    // Only the function's terminal statement is eligible: an earlier
+    // `return await` may be followed by cleanup on other paths.
+    let Some(Stmt::Return(ast::StmtReturn {
+        value: Some(value),
+        range: _,
+    })) = function_def.body.last()
+    else {
+        return;
+    };
+    let ast::Expr::Await(await_expr) = value.as_ref() else {
+        return;
+    };
+
+    let mut diagnostic = Diagnostic::new(UnnecessaryReturnAwait, await_expr.range());
+
+    // Delete the `await` keyword and trailing whitespace; if the awaited
+    // expression is parenthesized, the keyword isn't directly adjacent, so
+    // leave the rewrite to the user.
+    let deletion = TextRange::new(await_expr.start(), await_expr.value.start());
+    if checker.locator().slice(deletion).trim_end() == "await" {
+        diagnostic.set_fix(Fix::unsafe_edit(Edit::range_deletion(deletion)));
+    }
+
+    checker.diagnostics.push(diagnostic);
+}
@@ -0,0 +1,132 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Expr, Stmt};
+use ruff_python_semantic::SemanticModel;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for elapsed-time measurements computed by subtracting two
+/// `time.time()` readings.
+///
+/// ## Why is this bad?
+/// `time.time()` reports the wall clock, which can jump backwards or forwards
+/// when the system clock is adjusted (e.g., by NTP or daylight saving time),
+/// producing negative or wildly wrong durations. `time.monotonic()` is
+/// guaranteed to never go backwards and is intended for measuring elapsed
+/// time.
+///
+/// `time.time()` readings that are not subtracted from one another (e.g.,
+/// timestamps for logging) are not flagged.
+///
+/// ## Example
+/// ```python
+/// import time
+///
+/// start = time.time()
+/// do_work()
+/// elapsed = time.time() - start
+/// ```
+///
+/// Use instead:
+/// ```python
+/// import time
+///
+/// start = time.monotonic()
+/// do_work()
+/// elapsed = time.monotonic() - start
+/// ```
+///
+/// ## Fix safety
+/// This rule's fix is marked as unsafe, as it rewrites both readings to
+/// `time.monotonic()`, whose values are not comparable with wall-clock
+/// timestamps that may be derived from the same variables elsewhere.
+///
+/// ## References
+/// - [Python documentation: `time.monotonic`](https://docs.python.org/3/library/time.html#time.monotonic)
+#[violation]
+pub struct WallClockElapsedTime;
+
+impl Violation for WallClockElapsedTime {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("`time.time()` used to measure elapsed time; use `time.monotonic()` instead")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Replace with `time.monotonic()`".to_string())
+    }
+}
+
+/// RUF047
+pub(crate) fn wall_clock_elapsed_time(checker: &mut Checker, bin_op: &ast::ExprBinOp) {
+    let semantic = checker.semantic();
+
+    let left =
+        time_call(&bin_op.left, semantic).or_else(|| assigned_time_call(&bin_op.left, semantic));
+    let right =
+        time_call(&bin_op.right, semantic).or_else(|| assigned_time_call(&bin_op.right, semantic));
+    let (Some(left), Some(right)) = (left, right) else {
+        return;
+    };
+
+    let mut diagnostic = Diagnostic::new(WallClockElapsedTime, bin_op.range());
+
+    // Only rewrite the `time.time` attribute form, where `time.monotonic` is
+    // guaranteed to resolve; rewrite both readings so that they remain
+    // comparable.
+    if let (Some(first), Some(rest)) = (monotonic_edit(left), monotonic_edit(right)) {
+        if left.range() == right.range() {
+            diagnostic.set_fix(Fix::unsafe_edit(first));
+        } else {
+            diagnostic.set_fix(Fix::unsafe_edits(first, [rest]));
+        }
+    }
+
+    checker.diagnostics.push(diagnostic);
+}
+
+/// Return the call if the expression is a direct `time.time()` call.
+fn time_call<'a>(expr: &'a Expr, semantic: &SemanticModel) -> Option<&'a ast::ExprCall> {
+    let call = expr.as_call_expr()?;
+    if call.arguments.is_empty()
+        && semantic
+            .resolve_qualified_name(&call.func)
+            .is_some_and(|qualified_name| matches!(qualified_name.segments(), ["time", "time"]))
+    {
+        Some(call)
+    } else {
+        None
+    }
+}
+
+/// Return the initializing call if the expression is a name whose binding was
+/// assigned from a direct `time.time()` call.
+fn assigned_time_call<'a>(
+    expr: &'a Expr,
+    semantic: &'a SemanticModel,
+) -> Option<&'a ast::ExprCall> {
+    let name = expr.as_name_expr()?;
+    let binding_id = semantic.resolve_name(name)?;
+    let Stmt::Assign(ast::StmtAssign { value, .. }) =
+        semantic.binding(binding_id).statement(semantic)?
+    else {
+        return None;
+    };
+    time_call(value, semantic)
+}
+
+/// Return an edit replacing the call's `time.time` attribute with
+/// `time.monotonic`, if the call uses the attribute form.
+fn monotonic_edit(call: &ast::ExprCall) -> Option<Edit> {
+    if !call.func.is_attribute_expr() {
+        return None;
+    }
+    Some(Edit::range_replacement(
+        "time.monotonic".to_string(),
+        call.func.range(),
+    ))
+}
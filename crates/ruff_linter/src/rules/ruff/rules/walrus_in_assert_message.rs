@@ -0,0 +1,67 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::helpers::any_over_expr;
+use ruff_python_ast::{self as ast, Expr};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `assert` statements whose test is a bare walrus assignment
+/// that's only referenced in the assertion message.
+///
+/// ## Why is this bad?
+/// In `assert (x := compute()), x`, the walrus binds a value solely so that
+/// the message can display it, while the assertion itself tests only the
+/// value's truthiness. The comma reads like a second condition, and the
+/// intent — compute, check, report — is clearer when split into an
+/// assignment followed by an `assert`.
+///
+/// ## Example
+/// ```python
+/// assert (result := compute()), result
+/// ```
+///
+/// Use instead:
+/// ```python
+/// result = compute()
+/// assert result, f"unexpected result: {result}"
+/// ```
+///
+/// ## References
+/// - [Python documentation: Assignment expressions](https://docs.python.org/3/reference/expressions.html#assignment-expressions)
+#[violation]
+pub struct WalrusInAssertMessage;
+
+impl Violation for WalrusInAssertMessage {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("Walrus assignment in `assert` test is only used in the message")
+    }
+}
+
+/// RUF035
+pub(crate) fn walrus_in_assert_message(checker: &mut Checker, stmt: &ast::StmtAssert) {
+    let Some(msg) = stmt.msg.as_deref() else {
+        return;
+    };
+
+    // Only flag a walrus that forms the entire test; a walrus nested in a
+    // larger condition (e.g., `assert (x := compute()) > 0, x`) is an
+    // intentional combination of assignment and check.
+    let Expr::Named(named) = stmt.test.as_ref() else {
+        return;
+    };
+
+    let Expr::Name(ast::ExprName { id, .. }) = named.target.as_ref() else {
+        return;
+    };
+
+    if any_over_expr(msg, &|expr| {
+        expr.as_name_expr().is_some_and(|name| name.id == *id)
+    }) {
+        checker
+            .diagnostics
+            .push(Diagnostic::new(WalrusInAssertMessage, named.range()));
+    }
+}
@@ -0,0 +1,184 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Decorator};
+use ruff_python_semantic::SemanticModel;
+use ruff_python_trivia::indentation_at_offset;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for decorator combinations and orderings that don't work:
+/// `@property` combined with `@staticmethod`, `@property` applied on top of
+/// `@classmethod`, and `@abstractmethod` applied anywhere but innermost.
+///
+/// ## Why is this bad?
+/// These decorators are order-sensitive. `@abstractmethod` must be applied
+/// first (i.e., listed closest to the `def`), or the method won't be
+/// registered as abstract. `@property` cannot be combined with
+/// `@staticmethod` at all, and only works under `@classmethod` (not above
+/// it) — and even that combination is deprecated since Python 3.11.
+///
+/// ## Example
+/// ```python
+/// class Foo(ABC):
+///     @abstractmethod
+///     @property
+///     def bar(self): ...
+/// ```
+///
+/// Use instead:
+/// ```python
+/// class Foo(ABC):
+///     @property
+///     @abstractmethod
+///     def bar(self): ...
+/// ```
+///
+/// ## Fix safety
+/// A fix is only offered for a misplaced `@abstractmethod`, where moving it
+/// below the other decorators is unambiguous. The fix is marked as unsafe,
+/// as reordering decorators changes the order in which they're applied.
+#[violation]
+pub struct WrongDecoratorOrder {
+    kind: DecoratorOrderKind,
+}
+
+impl Violation for WrongDecoratorOrder {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        match self.kind {
+            DecoratorOrderKind::AbstractmethodNotInnermost => {
+                format!("`@abstractmethod` must be the innermost decorator")
+            }
+            DecoratorOrderKind::PropertyWithStaticmethod => {
+                format!("`@property` cannot be combined with `@staticmethod`")
+            }
+            DecoratorOrderKind::PropertyAboveClassmethod => {
+                format!("`@property` must be applied before `@classmethod`")
+            }
+        }
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        match self.kind {
+            DecoratorOrderKind::AbstractmethodNotInnermost => {
+                Some(format!("Move `@abstractmethod` below the other decorators"))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum DecoratorOrderKind {
+    AbstractmethodNotInnermost,
+    PropertyWithStaticmethod,
+    PropertyAboveClassmethod,
+}
+
+/// Returns `true` if the decorator resolves to `abc.abstractmethod`.
+fn is_abstractmethod(decorator: &Decorator, semantic: &SemanticModel) -> bool {
+    semantic
+        .resolve_qualified_name(&decorator.expression)
+        .is_some_and(|qualified_name| {
+            matches!(qualified_name.segments(), ["abc", "abstractmethod"])
+        })
+}
+
+/// RUF038
+pub(crate) fn wrong_decorator_order(checker: &mut Checker, function_def: &ast::StmtFunctionDef) {
+    let decorator_list = &function_def.decorator_list;
+    if decorator_list.len() < 2 {
+        return;
+    }
+
+    if !checker.semantic().current_scope().kind.is_class() {
+        return;
+    }
+
+    let (property, staticmethod, classmethod, abstractmethod) = {
+        let semantic = checker.semantic();
+        let index_of = |builtin: &str| {
+            decorator_list
+                .iter()
+                .position(|decorator| semantic.match_builtin_expr(&decorator.expression, builtin))
+        };
+        (
+            index_of("property"),
+            index_of("staticmethod"),
+            index_of("classmethod"),
+            decorator_list
+                .iter()
+                .position(|decorator| is_abstractmethod(decorator, semantic)),
+        )
+    };
+
+    if let (Some(_), Some(staticmethod)) = (property, staticmethod) {
+        checker.diagnostics.push(Diagnostic::new(
+            WrongDecoratorOrder {
+                kind: DecoratorOrderKind::PropertyWithStaticmethod,
+            },
+            decorator_list[staticmethod].range(),
+        ));
+    } else if let (Some(property), Some(classmethod)) = (property, classmethod) {
+        // `@classmethod` must be outermost; `@property` listed above it is
+        // applied last, and produces a plain `property` over a classmethod.
+        if property < classmethod {
+            checker.diagnostics.push(Diagnostic::new(
+                WrongDecoratorOrder {
+                    kind: DecoratorOrderKind::PropertyAboveClassmethod,
+                },
+                decorator_list[property].range(),
+            ));
+        }
+    }
+
+    let Some(abstractmethod) = abstractmethod else {
+        return;
+    };
+    if abstractmethod == decorator_list.len() - 1 {
+        return;
+    }
+    // Only flag when the decorators applied before `@abstractmethod` are
+    // known to require it innermost; arbitrary decorators may compose fine.
+    if ![property, staticmethod, classmethod]
+        .into_iter()
+        .flatten()
+        .any(|index| index > abstractmethod)
+    {
+        return;
+    }
+
+    let decorator = &decorator_list[abstractmethod];
+    let mut diagnostic = Diagnostic::new(
+        WrongDecoratorOrder {
+            kind: DecoratorOrderKind::AbstractmethodNotInnermost,
+        },
+        decorator.range(),
+    );
+
+    // Move the decorator to the bottom of the stack, when it occupies its
+    // own line(s) and no comments would be displaced.
+    let full_range = checker.locator().full_lines_range(decorator.range());
+    let def_line_start = checker.locator().line_start(function_def.name.start());
+    if checker.locator().slice(full_range).trim().len() == checker.locator().slice(decorator).len()
+        && !checker.indexer().comment_ranges().intersects(full_range)
+    {
+        if let Some(indentation) = indentation_at_offset(decorator.start(), checker.locator()) {
+            let line = format!(
+                "{indentation}{}{}",
+                checker.locator().slice(decorator),
+                checker.stylist().line_ending().as_str()
+            );
+            diagnostic.set_fix(Fix::unsafe_edits(
+                Edit::range_deletion(full_range),
+                [Edit::insertion(line, def_line_start)],
+            ));
+        }
+    }
+
+    checker.diagnostics.push(diagnostic);
+}
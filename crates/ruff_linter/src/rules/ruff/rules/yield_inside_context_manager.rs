@@ -0,0 +1,79 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Expr, Stmt};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `yield` expressions inside a `with` block or `try`/`finally`
+/// in a generator function.
+///
+/// ## Why is this bad?
+/// A generator suspends at each `yield`. If the caller never resumes the
+/// generator — because it breaks out of the loop, or simply drops the
+/// reference — the `with` block's `__exit__` (or the `finally` clause) does
+/// not run until the generator is garbage-collected and a `GeneratorExit` is
+/// thrown into it. Until then, the resource stays open, and on interpreters
+/// without reference counting, cleanup may be delayed indefinitely.
+///
+/// Prefer acquiring the resource outside the generator, or consuming the
+/// resource eagerly before yielding.
+///
+/// This rule is advisory: generators that are always fully consumed, or
+/// explicitly closed, clean up promptly. It is in preview due to this
+/// false-positive potential.
+///
+/// ## Example
+/// ```python
+/// def read_lines(path):
+///     with open(path) as file:
+///         for line in file:
+///             yield line
+/// ```
+///
+/// Use instead:
+/// ```python
+/// def read_lines(path):
+///     with open(path) as file:
+///         lines = file.readlines()
+///     yield from lines
+/// ```
+///
+/// ## References
+/// - [Python documentation: `GeneratorExit`](https://docs.python.org/3/library/exceptions.html#GeneratorExit)
+/// - [PEP 533 – Deterministic cleanup for iterators](https://peps.python.org/pep-0533/)
+#[violation]
+pub struct YieldInsideContextManager;
+
+impl Violation for YieldInsideContextManager {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!(
+            "`yield` inside a `with` or `try`/`finally` may delay cleanup until the generator is finalized"
+        )
+    }
+}
+
+/// RUF070
+pub(crate) fn yield_inside_context_manager(checker: &mut Checker, expr: &Expr) {
+    if !checker.semantic().current_scope().kind.is_function() {
+        return;
+    }
+
+    let leaks = checker
+        .semantic()
+        .current_statements()
+        // Don't look beyond the enclosing function.
+        .take_while(|statement| !statement.is_function_def_stmt())
+        .any(|statement| match statement {
+            Stmt::With(_) => true,
+            Stmt::Try(ast::StmtTry { finalbody, .. }) => !finalbody.is_empty(),
+            _ => false,
+        });
+    if leaks {
+        checker
+            .diagnostics
+            .push(Diagnostic::new(YieldInsideContextManager, expr.range()));
+    }
+}
@@ -0,0 +1,68 @@
+//! Settings for the `ruff` plugin.
+
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+use crate::display_settings;
+use ruff_macros::CacheKey;
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize, CacheKey)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum OptionalStyle {
+    /// Prefer the PEP 604 `X | None` form.
+    #[default]
+    Pep604,
+    /// Prefer the `Optional[X]` form.
+    Optional,
+}
+
+impl Display for OptionalStyle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pep604 => write!(f, "pep604"),
+            Self::Optional => write!(f, "optional"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, CacheKey)]
+pub struct Settings {
+    pub dunder_all_case_insensitive: bool,
+    pub flag_duplicate_call_decorators: bool,
+    pub flag_idiomatic_conditional_imports: bool,
+    pub flag_multiline_return_parens: bool,
+    pub max_fstring_nesting_depth: u32,
+    pub optional_style: OptionalStyle,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            dunder_all_case_insensitive: false,
+            flag_duplicate_call_decorators: true,
+            flag_idiomatic_conditional_imports: false,
+            flag_multiline_return_parens: false,
+            max_fstring_nesting_depth: 2,
+            optional_style: OptionalStyle::default(),
+        }
+    }
+}
+
+impl Display for Settings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        display_settings! {
+            formatter = f,
+            namespace = "linter.ruff",
+            fields = [
+                self.dunder_all_case_insensitive,
+                self.flag_duplicate_call_decorators,
+                self.flag_idiomatic_conditional_imports,
+                self.flag_multiline_return_parens,
+                self.max_fstring_nesting_depth,
+                self.optional_style
+            ]
+        }
+        Ok(())
+    }
+}
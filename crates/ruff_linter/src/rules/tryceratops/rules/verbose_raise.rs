@@ -60,7 +60,10 @@ pub(crate) fn verbose_raise(checker: &mut Checker, handlers: &[ExceptHandler]) {
         }) = handler
         {
             let raises = {
-                let mut visitor = RaiseStatementVisitor::default();
+                let mut visitor = RaiseStatementVisitor {
+                    name: exception_name.as_str(),
+                    ..RaiseStatementVisitor::default()
+                };
                 visitor.visit_body(body);
                 visitor.raises
             };
@@ -88,6 +91,10 @@ pub(crate) fn verbose_raise(checker: &mut Checker, handlers: &[ExceptHandler]) {
 
 #[derive(Default)]
 struct RaiseStatementVisitor<'a> {
+    /// The name bound to the caught exception.
+    name: &'a str,
+    /// Whether the name has been rebound since the `except` clause.
+    rebound: bool,
     raises: Vec<&'a ast::StmtRaise>,
 }
 
@@ -95,7 +102,11 @@ impl<'a> StatementVisitor<'a> for RaiseStatementVisitor<'a> {
     fn visit_stmt(&mut self, stmt: &'a Stmt) {
         match stmt {
             Stmt::Raise(raise @ ast::StmtRaise { .. }) => {
-                self.raises.push(raise);
+                // Once the name has been rebound (e.g., `e = wrap(e)`), a bare
+                // `raise` would no longer raise the same exception.
+                if !self.rebound {
+                    self.raises.push(raise);
+                }
             }
             Stmt::Try(ast::StmtTry {
                 body, finalbody, ..
@@ -104,7 +115,41 @@ impl<'a> StatementVisitor<'a> for RaiseStatementVisitor<'a> {
                     walk_stmt(self, stmt);
                 }
             }
-            _ => walk_stmt(self, stmt),
+            _ => {
+                if rebinds_name(stmt, self.name) {
+                    self.rebound = true;
+                }
+                walk_stmt(self, stmt);
+            }
+        }
+    }
+}
+
+/// Returns `true` if the statement rebinds the given name.
+fn rebinds_name(stmt: &Stmt, name: &str) -> bool {
+    fn targets_name(expr: &Expr, name: &str) -> bool {
+        match expr {
+            Expr::Name(ast::ExprName { id, .. }) => id == name,
+            Expr::Tuple(ast::ExprTuple { elts, .. }) | Expr::List(ast::ExprList { elts, .. }) => {
+                elts.iter().any(|elt| targets_name(elt, name))
+            }
+            Expr::Starred(ast::ExprStarred { value, .. }) => targets_name(value, name),
+            _ => false,
+        }
+    }
+
+    match stmt {
+        Stmt::Assign(ast::StmtAssign { targets, .. }) => {
+            targets.iter().any(|target| targets_name(target, name))
         }
+        Stmt::AugAssign(ast::StmtAugAssign { target, .. })
+        | Stmt::AnnAssign(ast::StmtAnnAssign { target, .. })
+        | Stmt::For(ast::StmtFor { target, .. }) => targets_name(target, name),
+        Stmt::With(ast::StmtWith { items, .. }) => items.iter().any(|item| {
+            item.optional_vars
+                .as_deref()
+                .is_some_and(|target| targets_name(target, name))
+        }),
+        _ => false,
     }
 }
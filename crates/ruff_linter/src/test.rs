@@ -17,7 +17,7 @@ use ruff_python_ast::PySourceType;
 use ruff_python_codegen::Stylist;
 use ruff_python_index::Indexer;
 use ruff_python_parser::lexer::LexResult;
-use ruff_python_parser::AsMode;
+use ruff_python_parser::{AsMode, ParseError};
 use ruff_python_trivia::textwrap::dedent;
 use ruff_source_file::{Locator, SourceFileBuilder};
 use ruff_text_size::Ranged;
@@ -27,7 +27,7 @@ use crate::fix::{fix_file, FixResult};
 use crate::linter::{check_path, LinterResult, TokenSource};
 use crate::message::{Emitter, EmitterContext, Message, TextEmitter};
 use crate::packaging::detect_package_root;
-use crate::registry::AsRule;
+use crate::registry::{AsRule, Rule};
 use crate::rules::pycodestyle::rules::syntax_error;
 use crate::settings::types::UnsafeFixes;
 use crate::settings::{flags, LinterSettings};
@@ -84,6 +84,53 @@ pub(crate) fn assert_notebook_path(
     })
 }
 
+/// Run a single rule over a snippet of Python code, returning its diagnostics
+/// alongside any parse error.
+///
+/// Unlike [`test_contents`], this never panics: malformed input is reported
+/// via the returned [`ParseError`] and fixes are not applied, making it
+/// suitable for fuzz harnesses and rule development.
+pub fn check_rule(code: &str, rule: Rule) -> (Vec<Diagnostic>, Option<ParseError>) {
+    let path = Path::new("<filename>");
+    let settings = LinterSettings::for_rule(rule);
+    let source_kind = SourceKind::Python(code.to_string());
+    let source_type = PySourceType::default();
+    let tokens: Vec<LexResult> =
+        ruff_python_parser::tokenize(source_kind.source_code(), source_type.as_mode());
+    let locator = Locator::new(source_kind.source_code());
+    let stylist = Stylist::from_tokens(&tokens, &locator);
+    let indexer = Indexer::from_tokens(&tokens, &locator);
+    let directives = directives::extract_directives(
+        &tokens,
+        directives::Flags::from_settings(&settings),
+        &locator,
+        &indexer,
+    );
+    let LinterResult {
+        data: diagnostics,
+        error,
+    } = check_path(
+        path,
+        None,
+        &locator,
+        &stylist,
+        &indexer,
+        &directives,
+        &settings,
+        flags::Noqa::Enabled,
+        &source_kind,
+        source_type,
+        TokenSource::Tokens(tokens),
+    );
+    (
+        diagnostics
+            .into_iter()
+            .filter(|diagnostic| diagnostic.kind.rule() == rule)
+            .collect(),
+        error,
+    )
+}
+
 /// Run [`check_path`] on a snippet of Python code.
 pub fn test_snippet(contents: &str, settings: &LinterSettings) -> Vec<Message> {
     let path = Path::new("<filename>");
@@ -11,7 +11,7 @@ use ruff_python_stdlib::typing::{
 };
 use ruff_text_size::Ranged;
 
-use crate::analyze::type_inference::{PythonType, ResolvedPythonType};
+use crate::analyze::type_inference::{NumberLike, PythonType, ResolvedPythonType};
 use crate::model::SemanticModel;
 use crate::{Binding, BindingKind, Modules};
 
@@ -651,6 +651,134 @@ impl BuiltinTypeChecker for TupleChecker {
     const EXPR_TYPE: PythonType = PythonType::Tuple;
 }
 
+/// Check whether `annotation` names the given builtin scalar type, which
+/// (unlike the collection types) has no `typing` alias.
+fn match_scalar_annotation(annotation: &Expr, semantic: &SemanticModel, name: &str) -> bool {
+    semantic.match_builtin_expr(map_subscript(annotation), name)
+}
+
+/// Check whether `initializer` evaluates to the given builtin scalar type,
+/// either as a literal of that type or a call to the builtin constructor.
+fn match_scalar_initializer(
+    initializer: &Expr,
+    semantic: &SemanticModel,
+    name: &str,
+    expr_type: PythonType,
+) -> bool {
+    let init_type: ResolvedPythonType = initializer.into();
+    if matches!(init_type, ResolvedPythonType::Atom(atom) if atom == expr_type) {
+        return true;
+    }
+    let Expr::Call(ast::ExprCall { func, .. }) = initializer else {
+        return false;
+    };
+    semantic.match_builtin_expr(func, name)
+}
+
+struct IntChecker;
+
+impl TypeChecker for IntChecker {
+    fn match_annotation(annotation: &Expr, semantic: &SemanticModel) -> bool {
+        match_scalar_annotation(annotation, semantic, "int")
+    }
+
+    fn match_initializer(initializer: &Expr, semantic: &SemanticModel) -> bool {
+        match_scalar_initializer(
+            initializer,
+            semantic,
+            "int",
+            PythonType::Number(NumberLike::Integer),
+        )
+    }
+}
+
+struct StrChecker;
+
+impl TypeChecker for StrChecker {
+    fn match_annotation(annotation: &Expr, semantic: &SemanticModel) -> bool {
+        match_scalar_annotation(annotation, semantic, "str")
+    }
+
+    fn match_initializer(initializer: &Expr, semantic: &SemanticModel) -> bool {
+        match_scalar_initializer(initializer, semantic, "str", PythonType::String)
+    }
+}
+
+struct BytesChecker;
+
+impl TypeChecker for BytesChecker {
+    fn match_annotation(annotation: &Expr, semantic: &SemanticModel) -> bool {
+        match_scalar_annotation(annotation, semantic, "bytes")
+    }
+
+    fn match_initializer(initializer: &Expr, semantic: &SemanticModel) -> bool {
+        match_scalar_initializer(initializer, semantic, "bytes", PythonType::Bytes)
+    }
+}
+
+struct BoolChecker;
+
+impl TypeChecker for BoolChecker {
+    fn match_annotation(annotation: &Expr, semantic: &SemanticModel) -> bool {
+        match_scalar_annotation(annotation, semantic, "bool")
+    }
+
+    fn match_initializer(initializer: &Expr, semantic: &SemanticModel) -> bool {
+        match_scalar_initializer(
+            initializer,
+            semantic,
+            "bool",
+            PythonType::Number(NumberLike::Bool),
+        )
+    }
+}
+
+struct FloatChecker;
+
+impl TypeChecker for FloatChecker {
+    fn match_annotation(annotation: &Expr, semantic: &SemanticModel) -> bool {
+        match_scalar_annotation(annotation, semantic, "float")
+    }
+
+    fn match_initializer(initializer: &Expr, semantic: &SemanticModel) -> bool {
+        match_scalar_initializer(
+            initializer,
+            semantic,
+            "float",
+            PythonType::Number(NumberLike::Float),
+        )
+    }
+}
+
+pub struct PurePathChecker;
+
+impl TypeChecker for PurePathChecker {
+    fn match_annotation(annotation: &Expr, semantic: &SemanticModel) -> bool {
+        semantic
+            .resolve_qualified_name(annotation)
+            .is_some_and(|qualified_name| {
+                matches!(
+                    qualified_name.segments(),
+                    ["pathlib", "PurePath" | "PurePosixPath" | "PureWindowsPath"]
+                )
+            })
+    }
+
+    fn match_initializer(initializer: &Expr, semantic: &SemanticModel) -> bool {
+        let Expr::Call(ast::ExprCall { func, .. }) = initializer else {
+            return false;
+        };
+        semantic
+            .resolve_qualified_name(func)
+            .is_some_and(|qualified_name| {
+                matches!(
+                    qualified_name.segments(),
+                    ["pathlib", "PurePath" | "PurePosixPath" | "PureWindowsPath"]
+                )
+            })
+    }
+}
+
 pub struct IoBaseChecker;
 
 impl TypeChecker for IoBaseChecker {
@@ -760,6 +888,52 @@ pub fn is_tuple(binding: &Binding, semantic: &SemanticModel) -> bool {
     check_type::<TupleChecker>(binding, semantic)
 }
 
+/// Test whether the given binding can be considered an integer.
+///
+/// For this, we check what value might be associated with it through it's initialization and
+/// what annotation it has (we consider `int`).
+pub fn is_int(binding: &Binding, semantic: &SemanticModel) -> bool {
+    check_type::<IntChecker>(binding, semantic)
+}
+
+/// Test whether the given binding can be considered a string.
+///
+/// For this, we check what value might be associated with it through it's initialization and
+/// what annotation it has (we consider `str`).
+pub fn is_str(binding: &Binding, semantic: &SemanticModel) -> bool {
+    check_type::<StrChecker>(binding, semantic)
+}
+
+/// Test whether the given binding can be considered a boolean.
+///
+/// For this, we check what value might be associated with it through it's initialization and
+/// what annotation it has (we consider `bool`).
+pub fn is_bool(binding: &Binding, semantic: &SemanticModel) -> bool {
+    check_type::<BoolChecker>(binding, semantic)
+}
+
+/// Test whether the given binding can be considered a bytes object.
+///
+/// For this, we check what value might be associated with it through it's initialization and
+/// what annotation it has (we consider `bytes`).
+pub fn is_bytes(binding: &Binding, semantic: &SemanticModel) -> bool {
+    check_type::<BytesChecker>(binding, semantic)
+}
+
+/// Test whether the given binding can be considered a float.
+///
+/// For this, we check what value might be associated with it through it's initialization and
+/// what annotation it has (we consider `float`).
+pub fn is_float(binding: &Binding, semantic: &SemanticModel) -> bool {
+    check_type::<FloatChecker>(binding, semantic)
+}
+
+/// Test whether the given binding can be considered a `pathlib.PurePath` (or
+/// one of its platform-specific variants).
+pub fn is_pure_path(binding: &Binding, semantic: &SemanticModel) -> bool {
+    check_type::<PurePathChecker>(binding, semantic)
+}
+
 /// Test whether the given binding can be considered a file-like object (i.e., a type that
 /// implements `io.IOBase`).
 pub fn is_io_base(binding: &Binding, semantic: &SemanticModel) -> bool {
@@ -44,10 +44,10 @@ use crate::options::{
     Flake8BuiltinsOptions, Flake8ComprehensionsOptions, Flake8CopyrightOptions,
     Flake8ErrMsgOptions, Flake8GetTextOptions, Flake8ImplicitStrConcatOptions,
     Flake8ImportConventionsOptions, Flake8PytestStyleOptions, Flake8QuotesOptions,
-    Flake8SelfOptions, Flake8TidyImportsOptions, Flake8TypeCheckingOptions,
+    Flake8ReturnOptions, Flake8SelfOptions, Flake8TidyImportsOptions, Flake8TypeCheckingOptions,
     Flake8UnusedArgumentsOptions, FormatOptions, IsortOptions, LintCommonOptions, LintOptions,
     McCabeOptions, Options, Pep8NamingOptions, PyUpgradeOptions, PycodestyleOptions,
-    PydocstyleOptions, PyflakesOptions, PylintOptions,
+    PydocstyleOptions, PyflakesOptions, PylintOptions, RuffOptions,
 };
 use crate::settings::{
     FileResolverSettings, FormatterSettings, LineEnding, Settings, EXCLUDE, INCLUDE,
@@ -335,6 +335,10 @@ impl Configuration {
                     .flake8_quotes
                     .map(Flake8QuotesOptions::into_settings)
                     .unwrap_or_default(),
+                flake8_return: lint
+                    .flake8_return
+                    .map(Flake8ReturnOptions::into_settings)
+                    .unwrap_or_default(),
                 flake8_self: lint
                     .flake8_self
                     .map(Flake8SelfOptions::into_settings)
@@ -393,6 +397,10 @@ impl Configuration {
                     .pyupgrade
                     .map(PyUpgradeOptions::into_settings)
                     .unwrap_or_default(),
+                ruff: lint
+                    .ruff
+                    .map(RuffOptions::into_settings)
+                    .unwrap_or_default(),
             },
 
             formatter,
@@ -625,6 +633,7 @@ pub struct LintConfiguration {
     pub flake8_import_conventions: Option<Flake8ImportConventionsOptions>,
     pub flake8_pytest_style: Option<Flake8PytestStyleOptions>,
     pub flake8_quotes: Option<Flake8QuotesOptions>,
+    pub flake8_return: Option<Flake8ReturnOptions>,
     pub flake8_self: Option<Flake8SelfOptions>,
     pub flake8_tidy_imports: Option<Flake8TidyImportsOptions>,
     pub flake8_type_checking: Option<Flake8TypeCheckingOptions>,
@@ -637,6 +646,7 @@ pub struct LintConfiguration {
     pub pyflakes: Option<PyflakesOptions>,
     pub pylint: Option<PylintOptions>,
     pub pyupgrade: Option<PyUpgradeOptions>,
+    pub ruff: Option<RuffOptions>,
 }
 
 impl LintConfiguration {
@@ -730,6 +740,7 @@ impl LintConfiguration {
             flake8_import_conventions: options.common.flake8_import_conventions,
             flake8_pytest_style: options.common.flake8_pytest_style,
             flake8_quotes: options.common.flake8_quotes,
+            flake8_return: options.common.flake8_return,
             flake8_self: options.common.flake8_self,
             flake8_tidy_imports: options.common.flake8_tidy_imports,
             flake8_type_checking: options.common.flake8_type_checking,
@@ -742,6 +753,7 @@ impl LintConfiguration {
             pyflakes: options.common.pyflakes,
             pylint: options.common.pylint,
             pyupgrade: options.common.pyupgrade,
+            ruff: options.ruff,
         })
     }
 
@@ -1073,6 +1085,22 @@ impl LintConfiguration {
             }
         }
 
+        // Apply any convention-independent overrides from the `pydocstyle`
+        // settings: force-enabled rules are restored even if the convention
+        // ignored them, while force-disabled rules are removed outright.
+        if let Some(pydocstyle) = self.pydocstyle.as_ref() {
+            for selector in pydocstyle.force_enable.iter().flatten() {
+                for rule in selector.rules(&preview) {
+                    rules.enable(rule, fixable_set.contains(rule));
+                }
+            }
+            for selector in pydocstyle.force_disable.iter().flatten() {
+                for rule in selector.rules(&preview) {
+                    rules.disable(rule);
+                }
+            }
+        }
+
         // Validate that we didn't enable any incompatible rules. Use this awkward
         // approach to give each pair it's own `warn_user_once`.
         for (preferred, expendable, message) in INCOMPATIBLE_CODES {
@@ -1139,6 +1167,7 @@ impl LintConfiguration {
                 .combine(config.flake8_import_conventions),
             flake8_pytest_style: self.flake8_pytest_style.combine(config.flake8_pytest_style),
             flake8_quotes: self.flake8_quotes.combine(config.flake8_quotes),
+            flake8_return: self.flake8_return.combine(config.flake8_return),
             flake8_self: self.flake8_self.combine(config.flake8_self),
             flake8_tidy_imports: self.flake8_tidy_imports.combine(config.flake8_tidy_imports),
             flake8_type_checking: self
@@ -1155,6 +1184,7 @@ impl LintConfiguration {
             pyflakes: self.pyflakes.combine(config.pyflakes),
             pylint: self.pylint.combine(config.pylint),
             pyupgrade: self.pyupgrade.combine(config.pyupgrade),
+            ruff: self.ruff.combine(config.ruff),
         }
     }
 }
@@ -1382,6 +1412,10 @@ fn warn_about_deprecated_top_level_lint_options(
         used_options.push("flake8-quotes");
     }
 
+    if top_level_options.flake8_return.is_some() {
+        used_options.push("flake8-return");
+    }
+
     if top_level_options.flake8_self.is_some() {
         used_options.push("flake8-self");
     }
@@ -2039,4 +2073,34 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn pydocstyle_force_enable_and_disable() -> Result<()> {
+        use ruff_linter::rules::pydocstyle::settings::Convention;
+
+        let config = LintConfiguration {
+            rule_selections: vec![RuleSelection {
+                select: Some(vec![RuleSelector::from_str("D41").unwrap()]),
+                ..RuleSelection::default()
+            }],
+            pydocstyle: Some(PydocstyleOptions {
+                convention: Some(Convention::Google),
+                // D407 is excluded by the Google convention; force-enable it anyway.
+                force_enable: Some(vec![RuleSelector::from_str("D407").unwrap()]),
+                // D418 is included in the selection; force-disable it anyway.
+                force_disable: Some(vec![RuleSelector::from_str("D418").unwrap()]),
+                ..PydocstyleOptions::default()
+            }),
+            ..LintConfiguration::default()
+        };
+
+        let enabled = config
+            .as_rule_table(PreviewMode::Disabled)?
+            .iter_enabled()
+            .collect::<RuleSet>();
+        assert!(enabled.contains(Rule::from_code("D407").unwrap()));
+        assert!(!enabled.contains(Rule::from_code("D418").unwrap()));
+
+        Ok(())
+    }
 }
@@ -20,9 +20,9 @@ use ruff_linter::rules::pydocstyle::settings::Convention;
 use ruff_linter::rules::pylint::settings::ConstantType;
 use ruff_linter::rules::{
     flake8_copyright, flake8_errmsg, flake8_gettext, flake8_implicit_str_concat,
-    flake8_import_conventions, flake8_pytest_style, flake8_quotes, flake8_self,
+    flake8_import_conventions, flake8_pytest_style, flake8_quotes, flake8_return, flake8_self,
     flake8_tidy_imports, flake8_type_checking, flake8_unused_arguments, isort, mccabe, pep8_naming,
-    pycodestyle, pydocstyle, pyflakes, pylint, pyupgrade,
+    pycodestyle, pydocstyle, pyflakes, pylint, pyupgrade, ruff,
 };
 use ruff_linter::settings::types::{
     IdentifierPattern, PythonVersion, RequiredVersion, SerializationFormat,
@@ -486,6 +486,10 @@ pub struct LintOptions {
         "#
     )]
     pub preview: Option<bool>,
+
+    /// Options for the `ruff` plugin.
+    #[option_group]
+    pub ruff: Option<RuffOptions>,
 }
 
 /// Newtype wrapper for [`LintCommonOptions`] that allows customizing the JSON schema and omitting the fields from the [`OptionsMetadata`].
@@ -837,6 +841,10 @@ pub struct LintCommonOptions {
     #[option_group]
     pub flake8_quotes: Option<Flake8QuotesOptions>,
 
+    /// Options for the `flake8-return` plugin.
+    #[option_group]
+    pub flake8_return: Option<Flake8ReturnOptions>,
+
     /// Options for the `flake8_self` plugin.
     #[option_group]
     pub flake8_self: Option<Flake8SelfOptions>,
@@ -1122,12 +1130,25 @@ pub struct Flake8BuiltinsOptions {
     )]
     /// Ignore list of builtins.
     pub builtins_ignorelist: Option<Vec<String>>,
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[str]",
+        example = "extra-builtins = [\"List\", \"Dict\", \"Optional\"]"
+    )]
+    /// Additional names to treat as builtins when checking for shadowing,
+    /// e.g., `typing` names that a project considers reserved.
+    pub extra_builtins: Option<Vec<String>>,
 }
 
 impl Flake8BuiltinsOptions {
     pub fn into_settings(self) -> ruff_linter::rules::flake8_builtins::settings::Settings {
         ruff_linter::rules::flake8_builtins::settings::Settings {
             builtins_ignorelist: self.builtins_ignorelist.unwrap_or_default(),
+            extra_builtins: self
+                .extra_builtins
+                .unwrap_or_default()
+                .into_iter()
+                .collect(),
         }
     }
 }
@@ -1590,6 +1611,32 @@ impl Flake8QuotesOptions {
     }
 }
 
+#[derive(
+    Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize, OptionsMetadata, CombineOptions,
+)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Flake8ReturnOptions {
+    /// Whether to flag annotated assignments (e.g., `x: int = foo()`) that
+    /// immediately precede a `return` of the assigned variable
+    /// (`unnecessary-assign`, `RET504`). By default, such assignments are
+    /// exempt, as the annotation may serve as intentional documentation.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = "flag-annotated-assigns = true"
+    )]
+    pub flag_annotated_assigns: Option<bool>,
+}
+
+impl Flake8ReturnOptions {
+    pub fn into_settings(self) -> flake8_return::settings::Settings {
+        flake8_return::settings::Settings {
+            flag_annotated_assigns: self.flag_annotated_assigns.unwrap_or_default(),
+        }
+    }
+}
+
 #[derive(
     Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize, OptionsMetadata, CombineOptions,
 )]
@@ -2727,6 +2774,40 @@ pub struct PydocstyleOptions {
     )]
     pub convention: Option<Convention>,
 
+    /// A list of rules to disable, regardless of the active `convention`.
+    ///
+    /// Unlike the top-level `ignore` option, this list is applied after the
+    /// convention's exclusions, making it a convenient place to prune
+    /// individual section rules without restating the full selection.
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[RuleSelector]",
+        example = r#"
+            convention = "numpy"
+            # Use the NumPy convention, but don't require a newline after each section name.
+            force-disable = ["D406"]
+        "#
+    )]
+    pub force_disable: Option<Vec<RuleSelector>>,
+
+    /// A list of rules to enable, regardless of the active `convention`.
+    ///
+    /// A convention disables every rule it doesn't include, and only a fully
+    /// qualified rule code in `select` can override that. This option
+    /// re-enables the listed rules on top of the convention, which is useful
+    /// for cherry-picking section rules from another convention (e.g.,
+    /// enforcing NumPy-style dashed underlines under the `google` convention).
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[RuleSelector]",
+        example = r#"
+            convention = "google"
+            # Use the Google convention, but also require NumPy-style dashed underlines.
+            force-enable = ["D407"]
+        "#
+    )]
+    pub force_enable: Option<Vec<RuleSelector>>,
+
     /// Ignore docstrings for functions or methods decorated with the
     /// specified fully-qualified decorators.
     #[option(
@@ -2872,6 +2953,15 @@ pub struct PylintOptions {
     /// (see: `PLR1702`).
     #[option(default = r"5", value_type = "int", example = r"max-nested-blocks = 5")]
     pub max_nested_blocks: Option<usize>,
+
+    /// Minimum number of equality comparisons against the same value required
+    /// to suggest a membership test (see: `PLR1714`).
+    #[option(
+        default = r"2",
+        value_type = "int",
+        example = r"min-repeated-comparisons = 3"
+    )]
+    pub min_repeated_comparisons: Option<usize>,
 }
 
 impl PylintOptions {
@@ -2896,6 +2986,9 @@ impl PylintOptions {
                 .unwrap_or(defaults.max_public_methods),
             max_locals: self.max_locals.unwrap_or(defaults.max_locals),
             max_nested_blocks: self.max_nested_blocks.unwrap_or(defaults.max_nested_blocks),
+            min_repeated_comparisons: self
+                .min_repeated_comparisons
+                .unwrap_or(defaults.min_repeated_comparisons),
         }
     }
 }
@@ -2955,6 +3048,99 @@ impl PyUpgradeOptions {
     }
 }
 
+/// Options for the `ruff` plugin
+#[derive(
+    Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize, OptionsMetadata, CombineOptions,
+)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RuffOptions {
+    /// Whether to sort the entries of `__all__` case-insensitively, rather
+    /// than with the default isort-style ordering (`RUF022`).
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            dunder-all-case-insensitive = true
+        "#
+    )]
+    pub dunder_all_case_insensitive: Option<bool>,
+
+    /// Whether `RUF033` should also flag duplicate decorators that are calls
+    /// (e.g., two identical `@app.route("/")` decorators). Disable this for
+    /// frameworks in which repeated registration calls are intentional.
+    #[option(
+        default = "true",
+        value_type = "bool",
+        example = r#"
+            flag-duplicate-call-decorators = false
+        "#
+    )]
+    pub flag_duplicate_call_decorators: Option<bool>,
+
+    /// Whether `RUF068` should also flag the idiomatic accelerator pattern
+    /// (`try: import ujson as json except ImportError: import json`), in
+    /// which one branch imports the module the shared name refers to.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            flag-idiomatic-conditional-imports = true
+        "#
+    )]
+    pub flag_idiomatic_conditional_imports: Option<bool>,
+
+    /// Whether `RUF046` should also flag redundant parentheses around
+    /// multi-line `return` and `yield` values, where the parentheses enable
+    /// line continuation.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            flag-multiline-return-parens = true
+        "#
+    )]
+    pub flag_multiline_return_parens: Option<bool>,
+
+    /// The maximum permitted f-string nesting depth (`RUF048`). Has no
+    /// effect when the target version is below 3.12, where any nesting is
+    /// flagged.
+    #[option(
+        default = "2",
+        value_type = "int",
+        example = r#"
+            max-fstring-nesting-depth = 1
+        "#
+    )]
+    pub max_fstring_nesting_depth: Option<u32>,
+
+    /// The preferred style for optional annotations (`RUF036`): the PEP 604
+    /// `X | None` form, or the `Optional[X]` form.
+    #[option(
+        default = r#""pep604""#,
+        value_type = r#""pep604" | "optional""#,
+        example = r#"
+            optional-style = "optional"
+        "#
+    )]
+    pub optional_style: Option<ruff::settings::OptionalStyle>,
+}
+
+impl RuffOptions {
+    pub fn into_settings(self) -> ruff::settings::Settings {
+        ruff::settings::Settings {
+            dunder_all_case_insensitive: self.dunder_all_case_insensitive.unwrap_or_default(),
+            flag_duplicate_call_decorators: self.flag_duplicate_call_decorators.unwrap_or(true),
+            flag_idiomatic_conditional_imports: self
+                .flag_idiomatic_conditional_imports
+                .unwrap_or_default(),
+            flag_multiline_return_parens: self.flag_multiline_return_parens.unwrap_or_default(),
+            max_fstring_nesting_depth: self.max_fstring_nesting_depth.unwrap_or(2),
+            optional_style: self.optional_style.unwrap_or_default(),
+        }
+    }
+}
+
 /// Configures the way ruff formats your code.
 #[derive(
     Clone, Debug, PartialEq, Eq, Default, Deserialize, Serialize, OptionsMetadata, CombineOptions,